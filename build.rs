@@ -0,0 +1,37 @@
+// lists every add_day! registration for the tests in tests/, so each day gets its
+// own #[test] without anyone keeping a list of them up to date
+use std::{
+    env,
+    fs::{read_dir, read_to_string, write},
+    path::Path,
+};
+
+fn main() {
+    // a new year's module is declared in lib.rs
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    let mut days = vec![];
+    for entry in read_dir("src").unwrap().flatten() {
+        let mod_path = entry.path().join("mod.rs");
+        if !entry.file_name().to_string_lossy().starts_with('y') || !mod_path.exists() {
+            continue;
+        }
+        println!("cargo:rerun-if-changed={}", mod_path.display());
+        for line in read_to_string(&mod_path).unwrap().lines() {
+            let Some(registration) = line.trim().strip_prefix("add_day!(") else {
+                continue;
+            };
+            let mut args = registration.split(',').map(|arg| arg.trim().parse::<u32>());
+            if let (Some(Ok(year)), Some(Ok(day))) = (args.next(), args.next()) {
+                days.push((year, day));
+            }
+        }
+    }
+    days.sort();
+
+    let days = days
+        .iter()
+        .map(|(year, day)| format!("    y{year}_day{day} => ({year}, {day}),\n"))
+        .collect::<String>();
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("days.rs");
+    write(path, format!("days!(\n{days});\n")).unwrap();
+}
@@ -0,0 +1,2 @@
+#![no_main]
+advent_2023_fuzz::fuzz_day!(2023, 12);
@@ -0,0 +1,23 @@
+use advent_2023::{get_solution, Part};
+
+// solves both parts of whatever the fuzzer gives, as text even when it isn't utf-8.
+// an error is fine, but a panic is a crash (overflows too, as cargo fuzz builds with
+// debug assertions) and taking longer than -timeout is a hang, so run with e.g.
+//   cargo fuzz run y2023_day8 -- -timeout=5
+// generated inputs make a good starting corpus, e.g.
+//   cargo run -- gen --day 8 --seed 1 > fuzz/corpus/y2023_day8/seed1
+pub fn solve(year: u32, day: u32, data: &[u8]) {
+    let solution = get_solution(year, day).expect("every target is for a registered day");
+    let input = String::from_utf8_lossy(data);
+    for part in [Part::A, Part::B] {
+        let _ = (solution.part(part))(&input);
+    }
+}
+
+// a day's fuzz target, made by the new command from template/fuzz_target.rs
+#[macro_export]
+macro_rules! fuzz_day {
+    ($year: literal, $day: literal) => {
+        libfuzzer_sys::fuzz_target!(|data: &[u8]| $crate::solve($year, $day, data));
+    };
+}
@@ -0,0 +1,105 @@
+use anyhow::{bail, Error};
+use std::{
+    fmt,
+    fs::{create_dir_all, read_to_string, write},
+    path::PathBuf,
+};
+
+use crate::Part;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    // the site said it was right
+    Accepted,
+    // saved from a run with --record
+    Recorded,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    // differs from an accepted answer
+    Fail,
+    // differs from a recorded answer
+    Changed,
+    Unknown,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Check::Pass => "pass",
+            Check::Fail => "fail",
+            Check::Changed => "changed",
+            Check::Unknown => "unknown",
+        })
+    }
+}
+
+// known answers for the real input, one part per line in answers/{year}/day{day}.txt
+pub struct AnswerStore {
+    path: PathBuf,
+    answers: [Option<(Source, String)>; 2],
+}
+
+impl AnswerStore {
+    pub fn load(day: u32, year: u32) -> Result<Self, Error> {
+        let path: PathBuf = format!("answers/{year}/day{day}.txt").into();
+        let mut answers = [None, None];
+        if let Ok(data) = read_to_string(&path) {
+            for line in data.lines().filter(|line| !line.is_empty()) {
+                let mut split = line.splitn(3, '\t');
+                let (part, source, answer) = match (split.next(), split.next(), split.next()) {
+                    (Some(part), Some(source), Some(answer)) => (part, source, answer),
+                    _ => bail!("bad answer line '{}' in {}", line, path.display()),
+                };
+                let source = match source {
+                    "accepted" => Source::Accepted,
+                    "recorded" => Source::Recorded,
+                    _ => bail!("unknown answer source '{}' in {}", source, path.display()),
+                };
+                answers[part.parse::<Part>()?.index()] = Some((source, answer.into()));
+            }
+        }
+        Ok(AnswerStore { path, answers })
+    }
+
+    pub fn get(&self, part: Part) -> Option<&(Source, String)> {
+        self.answers[part.index()].as_ref()
+    }
+
+    // an accepted answer is never replaced by a recorded one
+    pub fn set(&mut self, part: Part, source: Source, answer: &str) {
+        let entry = &mut self.answers[part.index()];
+        if source == Source::Accepted || !matches!(entry, Some((Source::Accepted, _))) {
+            *entry = Some((source, answer.into()));
+        }
+    }
+
+    pub fn check(&self, part: Part, answer: Option<&str>) -> Check {
+        match (self.get(part), answer) {
+            (None, _) => Check::Unknown,
+            (Some((_, expected)), Some(answer)) if expected == answer => Check::Pass,
+            (Some((Source::Accepted, _)), _) => Check::Fail,
+            (Some((Source::Recorded, _)), _) => Check::Changed,
+        }
+    }
+
+    pub fn save(&self) -> Result<(), Error> {
+        let data = [Part::A, Part::B]
+            .into_iter()
+            .filter_map(|part| {
+                self.get(part).map(|(source, answer)| {
+                    let source = match source {
+                        Source::Accepted => "accepted",
+                        Source::Recorded => "recorded",
+                    };
+                    format!("{part}\t{source}\t{answer}\n")
+                })
+            })
+            .collect::<String>();
+        create_dir_all(self.path.parent().unwrap())?;
+        write(&self.path, data)?;
+        Ok(())
+    }
+}
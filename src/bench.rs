@@ -0,0 +1,115 @@
+use anyhow::{Context, Error};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs::{create_dir_all, read_to_string, write},
+    hint::black_box,
+    path::Path,
+    time::Instant,
+};
+
+use crate::{NoPartB, Part, Solution};
+
+// all in nanoseconds
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub p95: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<f64>) -> Self {
+        samples.sort_by(f64::total_cmp);
+        let len = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / len;
+        let percentile = |p: f64| samples[((len - 1.0) * p).round() as usize];
+        Stats {
+            min: samples[0],
+            median: percentile(0.5),
+            mean,
+            p95: percentile(0.95),
+            stddev: (samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / len).sqrt(),
+        }
+    }
+}
+
+// "a", "b" and "total" -> stats
+pub type DayStats = BTreeMap<String, Stats>;
+// day -> stats, saved as json
+pub type Baseline = BTreeMap<u32, DayStats>;
+
+// time each part over a number of runs, after a few untimed warmup runs. there's
+// only a total when both parts ran, so it's always comparable with a baseline's
+pub fn bench(
+    solution: &Solution,
+    data: &str,
+    parts: &[Part],
+    runs: usize,
+) -> Result<DayStats, Error> {
+    // day 25 has no part b to time, unless it's the only part asked for
+    let parts = match parts.len() {
+        1 => parts.to_vec(),
+        _ => parts
+            .iter()
+            .copied()
+            .filter(|&part| !matches!((solution.part(part))(data), Err(err) if err.is::<NoPartB>()))
+            .collect(),
+    };
+    for _ in 0..(runs / 10).max(1) {
+        for &part in &parts {
+            black_box((solution.part(part))(black_box(data)))
+                .with_context(|| format!("part {part}"))?;
+        }
+    }
+
+    let mut samples = vec![vec![]; parts.len()];
+    let mut totals = vec![];
+    for _ in 0..runs {
+        let mut total = 0.0;
+        for (index, &part) in parts.iter().enumerate() {
+            let start = Instant::now();
+            black_box((solution.part(part))(black_box(data)))?;
+            let duration = (Instant::now() - start).as_nanos() as f64;
+            samples[index].push(duration);
+            total += duration;
+        }
+        totals.push(total);
+    }
+
+    let totals = (parts.len() > 1).then(|| ("total".into(), totals));
+    Ok(parts
+        .iter()
+        .map(|part| part.to_string())
+        .zip(samples)
+        .chain(totals)
+        .map(|(name, samples)| (name, Stats::new(samples)))
+        .collect())
+}
+
+pub fn load_baseline(path: &Path) -> Result<Baseline, Error> {
+    match read_to_string(path) {
+        Ok(data) => Ok(serde_json::from_str(&data)?),
+        Err(_) => Ok(Baseline::default()),
+    }
+}
+
+pub fn save_baseline(path: &Path, baseline: &Baseline) -> Result<(), Error> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    write(path, serde_json::to_string_pretty(baseline)?)?;
+    Ok(())
+}
+
+pub fn nanos_to_str(nanos: f64) -> String {
+    if nanos < 1_000.0 {
+        format!("{:.0}ns", nanos)
+    } else if nanos < 1_000_000.0 {
+        format!("{:.1}μs", nanos / 1_000.0)
+    } else {
+        format!("{:.2}ms", nanos / 1_000_000.0)
+    }
+}
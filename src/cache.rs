@@ -0,0 +1,210 @@
+use anyhow::{anyhow, bail, Error};
+use rustc_hash::FxHasher;
+use std::{
+    fmt,
+    fs::{create_dir_all, metadata, read_dir, read_to_string, remove_file, write},
+    future::Future,
+    hash::Hasher,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, SystemTime},
+};
+
+// where inputs and everything else kept per day live, laid out as {dir}/{year}/day{day}.*
+#[derive(Clone, Debug)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+// when an input was fetched and what it hashed to, kept next to it in day{day}.meta
+#[derive(Debug, Clone, Copy)]
+pub struct Meta {
+    pub fetched: i64,
+    pub hash: u64,
+}
+
+impl fmt::Display for Meta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}\t{:016x}", self.fetched, self.hash)
+    }
+}
+
+impl FromStr for Meta {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (fetched, hash) = s
+            .trim()
+            .split_once('\t')
+            .ok_or_else(|| anyhow!("bad meta line '{}'", s))?;
+        Ok(Meta {
+            fetched: fetched.parse()?,
+            hash: u64::from_str_radix(hash, 16)?,
+        })
+    }
+}
+
+// the site sends these instead of an input when logged out or asked too often
+const NOT_INPUT_MARKERS: [&str; 4] = [
+    "<!DOCTYPE",
+    "<html",
+    "Puzzle inputs differ by user",
+    "Please don't repeatedly request",
+];
+
+pub fn check_input(data: &str) -> Result<(), Error> {
+    if data.trim().is_empty() {
+        bail!("input is empty");
+    }
+    if let Some(marker) = NOT_INPUT_MARKERS
+        .iter()
+        .find(|marker| data.contains(*marker))
+    {
+        bail!("not a puzzle input (contains '{}')", marker);
+    }
+    Ok(())
+}
+
+pub fn hash_input(data: &str) -> u64 {
+    let mut hasher = FxHasher::default();
+    hasher.write(data.as_bytes());
+    hasher.finish()
+}
+
+impl Cache {
+    pub fn new(dir: &Path) -> Self {
+        Cache { dir: dir.into() }
+    }
+
+    pub fn input_path(&self, day: u32, year: u32) -> PathBuf {
+        self.dir.join(format!("{year}/day{day}.tmp"))
+    }
+
+    pub fn meta_path(&self, day: u32, year: u32) -> PathBuf {
+        self.dir.join(format!("{year}/day{day}.meta"))
+    }
+
+    pub fn puzzle_path(&self, day: u32, year: u32) -> PathBuf {
+        self.dir.join(format!("{year}/day{day}.md"))
+    }
+
+    pub fn verdicts_path(&self, day: u32, year: u32) -> PathBuf {
+        self.dir.join(format!("{year}/day{day}.verdicts"))
+    }
+
+    pub fn bench_path(&self, year: u32) -> PathBuf {
+        self.dir.join(format!("{year}/bench.json"))
+    }
+
+    pub fn leaderboard_path(&self, year: u32, id: u64) -> PathBuf {
+        self.dir.join(format!("{year}/leaderboard{id}.json"))
+    }
+
+    pub fn personal_stats_path(&self, year: u32) -> PathBuf {
+        self.dir.join(format!("{year}/stats.html"))
+    }
+
+    // a page kept so the site is asked for it at most every max_age, falling back
+    // to an older copy if asking fails. fetch should error on anything not worth keeping
+    pub async fn fetch_page<F, Fut>(
+        &self,
+        path: &Path,
+        max_age: Duration,
+        fetch: F,
+    ) -> Result<String, Error>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<String, Error>>,
+    {
+        let age = metadata(path)
+            .and_then(|meta| meta.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+        if age.is_some_and(|age| age < max_age) {
+            return Ok(read_to_string(path)?);
+        }
+        match (fetch().await, age) {
+            (Ok(data), _) => {
+                create_dir_all(path.parent().unwrap())?;
+                write(path, &data)?;
+                Ok(data)
+            }
+            (Err(err), Some(age)) => {
+                tracing::error!(
+                    "{:#}, so using {} from {} minutes ago",
+                    err,
+                    path.display(),
+                    age.as_secs() / 60
+                );
+                Ok(read_to_string(path)?)
+            }
+            (Err(err), None) => Err(err),
+        }
+    }
+
+    // every year with a directory in the cache, oldest first
+    pub fn years(&self) -> Vec<u32> {
+        let mut years = match read_dir(&self.dir) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+                .collect(),
+            Err(_) => vec![],
+        };
+        years.sort();
+        years
+    }
+
+    // every day with a cached input for the year
+    pub fn days(&self, year: u32) -> Vec<u32> {
+        (1..=25)
+            .filter(|&day| self.input_path(day, year).exists())
+            .collect()
+    }
+
+    pub fn read_meta(&self, day: u32, year: u32) -> Result<Option<Meta>, Error> {
+        match read_to_string(self.meta_path(day, year)) {
+            Ok(data) => Ok(Some(data.parse()?)),
+            Err(_) => Ok(None),
+        }
+    }
+
+    // refuses anything that isn't a puzzle input, so it isn't cached forever
+    pub fn write_input(&self, day: u32, year: u32, data: &str, fetched: i64) -> Result<(), Error> {
+        check_input(data)?;
+        let path = self.input_path(day, year);
+        create_dir_all(path.parent().unwrap())?;
+        write(path, data)?;
+        let meta = Meta {
+            fetched,
+            hash: hash_input(data),
+        };
+        write(self.meta_path(day, year), format!("{}\n", meta))?;
+        Ok(())
+    }
+
+    // errors describing what's wrong with a cached input
+    pub fn verify(&self, day: u32, year: u32) -> Result<Option<Meta>, Error> {
+        let data = read_to_string(self.input_path(day, year))?;
+        check_input(&data)?;
+        let meta = self.read_meta(day, year)?;
+        if let Some(meta) = meta {
+            if meta.hash != hash_input(&data) {
+                bail!("changed since it was fetched");
+            }
+        }
+        Ok(meta)
+    }
+
+    // removes the input and its meta, but not verdicts or bench results
+    pub fn clear(&self, day: u32, year: u32) -> Result<bool, Error> {
+        let path = self.input_path(day, year);
+        if !path.exists() {
+            return Ok(false);
+        }
+        remove_file(path)?;
+        let meta_path = self.meta_path(day, year);
+        if meta_path.exists() {
+            remove_file(meta_path)?;
+        }
+        Ok(true)
+    }
+}
@@ -0,0 +1,53 @@
+use clap::ValueEnum;
+use serde::Deserialize;
+use std::io::{self, IsTerminal, Write};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Colour {
+    /// When writing to a terminal and NO_COLOR isn't set
+    Auto,
+    Always,
+    Never,
+}
+
+impl Colour {
+    pub fn enabled(&self, stream: &impl IsTerminal) -> bool {
+        match self {
+            Colour::Auto => stream.is_terminal() && std::env::var_os("NO_COLOR").is_none(),
+            Colour::Always => true,
+            Colour::Never => false,
+        }
+    }
+}
+
+// everything is logged with ansi colour codes in it, so this drops them on the
+// way out when colour is off
+pub struct NoColour<W>(pub W);
+
+impl<W: Write> Write for NoColour<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0
+            .write_all(strip(&String::from_utf8_lossy(buf)).as_bytes())?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+pub fn strip(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("\x1b[") {
+        stripped.push_str(&rest[..start]);
+        rest = &rest[start..];
+        match rest.find('m') {
+            Some(end) => rest = &rest[end + 1..],
+            None => break,
+        }
+    }
+    stripped.push_str(rest);
+    stripped
+}
@@ -0,0 +1,88 @@
+use anyhow::{Context, Error};
+use clap::{parser::ValueSource, ArgMatches};
+use serde::Deserialize;
+use std::{fs::read_to_string, path::PathBuf};
+
+use crate::{colour::Colour, Args, Format};
+
+// defaults for the runner from aoc.toml files, e.g.
+//   session_file = "session.txt"
+//   user_agent = "github.com/someone/aoc by someone@example.com"
+//   cache_dir = "cache"
+//   year = 2023
+//   format = "text"
+//   colour = "auto"
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session_file: Option<PathBuf>,
+    pub user_agent: Option<String>,
+    pub cache_dir: Option<PathBuf>,
+    pub year: Option<u32>,
+    pub format: Option<Format>,
+    pub colour: Option<Colour>,
+}
+
+const FILE_NAME: &str = "aoc.toml";
+
+// the project's aoc.toml, then the user's, each overriding the one before
+// (environment variables and flags then override both)
+fn paths() -> Vec<PathBuf> {
+    let mut paths = vec![PathBuf::from(FILE_NAME)];
+    paths.extend(dirs::config_dir().map(|dir| dir.join("aoc").join(FILE_NAME)));
+    paths
+}
+
+impl Config {
+    pub fn load() -> Result<Self, Error> {
+        let mut config = Config::default();
+        for path in paths() {
+            let Ok(data) = read_to_string(&path) else {
+                continue;
+            };
+            let layer: Config =
+                toml::from_str(&data).with_context(|| format!("bad config {}", path.display()))?;
+            config = layer.over(config);
+        }
+        Ok(config)
+    }
+
+    fn over(self, under: Config) -> Self {
+        Config {
+            session_file: self.session_file.or(under.session_file),
+            user_agent: self.user_agent.or(under.user_agent),
+            cache_dir: self.cache_dir.or(under.cache_dir),
+            year: self.year.or(under.year),
+            format: self.format.or(under.format),
+            colour: self.colour.or(under.colour),
+        }
+    }
+
+    // fills in whatever wasn't given by a flag or environment variable
+    pub fn apply(self, args: &mut Args, matches: &ArgMatches) {
+        let unset = |id| {
+            !matches!(
+                matches.value_source(id),
+                Some(ValueSource::CommandLine | ValueSource::EnvVariable)
+            )
+        };
+        if let (true, Some(session_file)) = (unset("session_file"), self.session_file) {
+            args.session_file = session_file;
+        }
+        if let (true, Some(user_agent)) = (unset("user_agent"), self.user_agent) {
+            args.user_agent = Some(user_agent);
+        }
+        if let (true, Some(cache_dir)) = (unset("cache_dir"), self.cache_dir) {
+            args.cache_dir = cache_dir;
+        }
+        if let (true, Some(year)) = (unset("year"), self.year) {
+            args.year = Some(year);
+        }
+        if let (true, Some(format)) = (unset("format"), self.format) {
+            args.format = format;
+        }
+        if let (true, Some(colour)) = (unset("colour"), self.colour) {
+            args.colour = colour;
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use advent_2023::{
+    fixtures::Example, get_reference, get_solution, Answer, Part, Reference, Solution,
+};
+use anyhow::Error;
+use std::{ops::Range, time::Duration};
+
+use crate::supervisor::supervise;
+
+// an input the solution doesn't give the reference's answer for
+pub struct Disagreement {
+    pub part: Part,
+    pub input: String,
+    // the error if it didn't answer
+    pub solution: Result<String, String>,
+    pub reference: String,
+}
+
+// what running both on an input found
+pub enum Comparison {
+    Agreed,
+    Disagreed(Disagreement),
+    // the reference couldn't answer, so there was nothing to check the solution against
+    Skipped(Error),
+}
+
+// runs a day's solution and its reference side by side, with the same params
+pub struct Checker {
+    solution: &'static Solution,
+    reference: &'static Reference,
+    params: Vec<(String, i64)>,
+    timeout: Option<Duration>,
+}
+
+impl Checker {
+    pub fn new(
+        year: u32,
+        day: u32,
+        params: Vec<(String, i64)>,
+        timeout: Option<Duration>,
+    ) -> Result<Self, Error> {
+        Ok(Checker {
+            solution: get_solution(year, day)?,
+            reference: get_reference(year, day)?,
+            params,
+            timeout,
+        })
+    }
+
+    // the reference is the one trusted, so its failures say nothing about the solution
+    pub async fn check(&self, part: Part, input: &str) -> Comparison {
+        let reference = match self.solve(self.reference.solve, part, input).await {
+            Ok(reference) => reference,
+            Err(err) => return Comparison::Skipped(err),
+        };
+        let solution = self.solve(self.solution.solve_example, part, input).await;
+        match solution {
+            Ok(answer) if answer == reference => Comparison::Agreed,
+            solution => Comparison::Disagreed(Disagreement {
+                part,
+                input: input.into(),
+                solution: solution.map_err(|err| format!("{:#}", err)),
+                reference,
+            }),
+        }
+    }
+
+    async fn solve(
+        &self,
+        solve: fn(Part, &Example) -> Result<Answer, Error>,
+        part: Part,
+        input: &str,
+    ) -> Result<String, Error> {
+        let example = self
+            .params
+            .iter()
+            .fold(Example::new("diff", input), |example, (name, value)| {
+                example.with_param(name, *value)
+            });
+        supervise(self.timeout, move || {
+            solve(part, &example).map(|answer| answer.to_string())
+        })
+        .await?
+    }
+
+    // cuts out lines, then columns if it's a grid, for as long as they still disagree,
+    // trying big cuts first
+    pub async fn minimise(&self, mut found: Disagreement) -> Disagreement {
+        for columns in [false, true] {
+            let mut chunk = pieces(&found.input, columns).div_ceil(2);
+            while chunk > 0 {
+                let mut start = 0;
+                let mut cut_any = false;
+                while start < pieces(&found.input, columns) {
+                    let smaller = cut(&found.input, columns, start..start + chunk);
+                    match self.check(found.part, &smaller).await {
+                        Comparison::Disagreed(disagreement) => {
+                            found = disagreement;
+                            cut_any = true;
+                        }
+                        Comparison::Agreed | Comparison::Skipped(_) => start += chunk,
+                    }
+                }
+                if !cut_any {
+                    chunk /= 2;
+                }
+            }
+        }
+        found
+    }
+}
+
+// lines, or columns when every line is as long as the others
+fn pieces(input: &str, columns: bool) -> usize {
+    let mut lines = input.lines().filter(|line| !line.is_empty());
+    if !columns {
+        input.lines().count()
+    } else {
+        let width = lines.next().map_or(0, str::len);
+        if lines.all(|line| line.len() == width) && input.is_ascii() {
+            width
+        } else {
+            0
+        }
+    }
+}
+
+fn cut(input: &str, columns: bool, range: Range<usize>) -> String {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(index, _)| columns || !range.contains(index))
+        .map(|(_, line)| {
+            if columns && !line.is_empty() {
+                let (start, end) = (range.start.min(line.len()), range.end.min(line.len()));
+                format!("{}{}\n", &line[..start], &line[end..])
+            } else {
+                format!("{line}\n")
+            }
+        })
+        .collect()
+}
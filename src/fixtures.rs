@@ -0,0 +1,244 @@
+use anyhow::{anyhow, bail, Context, Error};
+use itertools::Itertools;
+use std::{
+    fmt,
+    fs::{create_dir_all, read_to_string, write},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use crate::{Answer, Part};
+
+// an example from the puzzle text, and the answers it should give
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    // numbers the puzzle text changes for the example, like how many steps to take
+    pub params: Vec<(String, i64)>,
+    pub expected: [Option<String>; 2],
+}
+
+impl Example {
+    pub fn new(name: &str, input: &str) -> Self {
+        Example {
+            name: name.into(),
+            input: input.into(),
+            params: vec![],
+            expected: [None, None],
+        }
+    }
+
+    pub fn part_a(mut self, answer: impl fmt::Display) -> Self {
+        self.expected[Part::A.index()] = Some(answer.to_string());
+        self
+    }
+
+    pub fn part_b(mut self, answer: impl fmt::Display) -> Self {
+        self.expected[Part::B.index()] = Some(answer.to_string());
+        self
+    }
+
+    pub fn with_param(mut self, name: &str, value: i64) -> Self {
+        self.params.push((name.into(), value));
+        self
+    }
+
+    // what the real puzzle uses when the example doesn't say
+    pub fn param_or(&self, name: &str, default: i64) -> i64 {
+        self.params
+            .iter()
+            .find(|(param, _)| param == name)
+            .map_or(default, |(_, value)| *value)
+    }
+}
+
+// one part of an example, and what it should have given
+#[derive(Debug, Clone)]
+pub struct Checked {
+    pub example: String,
+    pub part: Part,
+    pub got: String,
+    pub expected: String,
+    pub duration: Duration,
+}
+
+impl Checked {
+    pub fn passed(&self) -> bool {
+        self.got == self.expected
+    }
+}
+
+// every example checked, when any of them failed
+#[derive(Debug)]
+pub struct TestFailed {
+    pub checked: Vec<Checked>,
+}
+
+impl fmt::Display for TestFailed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed test, {}",
+            self.checked
+                .iter()
+                .filter(|checked| !checked.passed())
+                .map(|checked| format!(
+                    "example '{}' part {} got '{}' expected '{}'",
+                    checked.example, checked.part, checked.got, checked.expected
+                ))
+                .join(", ")
+        )
+    }
+}
+
+impl std::error::Error for TestFailed {}
+
+// every example checked, when they all passed but a later step failed, kept as
+// context on that step's error
+#[derive(Debug)]
+pub struct TestPassed {
+    pub checked: Vec<Checked>,
+}
+
+impl fmt::Display for TestPassed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "passed {} examples", self.checked.len())
+    }
+}
+
+// the error and its causes, like {:#} but leaving out a TestPassed
+pub fn error_message(err: &Error) -> String {
+    let skip = err.downcast_ref::<TestPassed>().is_some() as usize;
+    err.chain().skip(skip).join(": ")
+}
+
+// every part of every example that has an expected answer, carrying on past
+// wrong answers so they're all reported in a TestFailed
+pub fn check(
+    examples: &[Example],
+    solve: impl Fn(Part, &Example) -> Result<Answer, Error>,
+) -> Result<Vec<Checked>, Error> {
+    let mut checked = vec![];
+    for example in examples {
+        for part in [Part::A, Part::B] {
+            let Some(expected) = &example.expected[part.index()] else {
+                continue;
+            };
+            let start = Instant::now();
+            let got = solve(part, example)
+                .with_context(|| format!("example '{}' part {part}", example.name))?;
+            checked.push(Checked {
+                example: example.name.clone(),
+                part,
+                got: got.to_string(),
+                expected: expected.clone(),
+                duration: Instant::now() - start,
+            });
+        }
+    }
+    if checked.iter().all(Checked::passed) {
+        Ok(checked)
+    } else {
+        Err(TestFailed { checked }.into())
+    }
+}
+
+// examples for a day live in fixtures/{year}/day{day}.txt, each one being a
+// ">>> name" line, any ">>> param = value" lines, the input, then ">>> a = answer"
+// and/or ">>> b = answer"
+const MARKER: &str = ">>> ";
+
+// found from the crate's own directory, not the working one, so solvers that load
+// their examples still can when the library is used from somewhere else
+pub fn path(day: u32, year: u32) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("fixtures/{year}/day{day}.txt"))
+}
+
+pub fn load(day: u32, year: u32) -> Result<Vec<Example>, Error> {
+    let path = path(day, year);
+    let data =
+        read_to_string(&path).map_err(|_| anyhow!("cannot find fixture {}", path.display()))?;
+    let mut examples: Vec<Example> = vec![];
+    for line in data.lines() {
+        let Some(header) = line.strip_prefix(MARKER) else {
+            match examples.last_mut() {
+                Some(example) => {
+                    example.input.push_str(line);
+                    example.input.push('\n');
+                }
+                None => bail!("input before the first example in {}", path.display()),
+            }
+            continue;
+        };
+        match (header.split_once(" = "), examples.last_mut()) {
+            (Some((key, value)), Some(example)) => match key.parse::<Part>() {
+                Ok(part) => example.expected[part.index()] = Some(value.into()),
+                Err(_) => {
+                    let value = value.parse().map_err(|_| {
+                        anyhow!("bad value for {} in {}: '{}'", key, path.display(), value)
+                    })?;
+                    example.params.push((key.into(), value));
+                }
+            },
+            (Some(_), None) => bail!("answer before the first example in {}", path.display()),
+            (None, _) => examples.push(Example::new(header, "")),
+        }
+    }
+    Ok(examples)
+}
+
+// adds fetched examples to ones already in a fixture, keeping any hand edits. one
+// with the same input only gets the answers it was missing (like part b's, once
+// that's unlocked), anything else is added at the end. gives how many changed
+pub fn merge(examples: &mut Vec<Example>, fetched: Vec<Example>) -> usize {
+    let mut changed = 0;
+    for example in fetched {
+        match examples
+            .iter_mut()
+            .find(|existing| existing.input.trim() == example.input.trim())
+        {
+            Some(existing) => {
+                let mut filled = false;
+                for (answer, fetched) in existing.expected.iter_mut().zip(example.expected) {
+                    if answer.is_none() && fetched.is_some() {
+                        *answer = fetched;
+                        filled = true;
+                    }
+                }
+                changed += filled as usize;
+            }
+            None => {
+                examples.push(example);
+                changed += 1;
+            }
+        }
+    }
+    changed
+}
+
+pub fn save(day: u32, year: u32, examples: &[Example]) -> Result<PathBuf, Error> {
+    let path = path(day, year);
+    let data = examples
+        .iter()
+        .map(|example| {
+            let mut text = format!("{MARKER}{}\n", example.name);
+            for (name, value) in example.params.iter() {
+                text.push_str(&format!("{MARKER}{name} = {value}\n"));
+            }
+            text.push_str(&example.input);
+            if !text.ends_with('\n') {
+                text.push('\n');
+            }
+            for part in [Part::A, Part::B] {
+                if let Some(answer) = &example.expected[part.index()] {
+                    text.push_str(&format!("{MARKER}{part} = {answer}\n"));
+                }
+            }
+            text
+        })
+        .collect::<String>();
+    create_dir_all(path.parent().unwrap())?;
+    write(&path, data)?;
+    Ok(path)
+}
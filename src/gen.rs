@@ -0,0 +1,90 @@
+use anyhow::{bail, Error};
+use fastrand::Rng;
+use glam::{ivec2, IVec2};
+use itertools::Itertools;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::get_solution;
+
+// a random input for a day in the puzzle's format, the same every time for a seed.
+// size is roughly how many lines there are, or how wide a map is
+pub fn generate(year: u32, day: u32, seed: u64, size: usize) -> Result<String, Error> {
+    if size == 0 {
+        bail!("size must be above 0");
+    }
+    let solution = get_solution(year, day)?;
+    Ok((solution.generate)(&mut Rng::with_seed(seed), size))
+}
+
+// lowercase letters that aren't in taken yet, and then are
+pub fn name(rng: &mut Rng, len: usize, taken: &mut FxHashSet<String>) -> String {
+    loop {
+        let name = (0..len).map(|_| rng.lowercase()).collect::<String>();
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+// a loop that never touches itself, as the corners it goes through one step at a
+// time, clockwise. it's the outline of a random tree of width by height nodes drawn
+// with lines a cell thick, so fits in 2 * width by 2 * height
+pub fn outline(rng: &mut Rng, width: i32, height: i32) -> Vec<IVec2> {
+    // a random spanning tree, joining nodes in a random order unless already joined
+    let mut joins = (0..width)
+        .cartesian_product(0..height)
+        .flat_map(|(x, y)| {
+            [
+                (ivec2(x, y), ivec2(x + 1, y)),
+                (ivec2(x, y), ivec2(x, y + 1)),
+            ]
+        })
+        .filter(|(_, to)| to.x < width && to.y < height)
+        .collect_vec();
+    rng.shuffle(&mut joins);
+    let mut group = FxHashMap::default();
+    let find = |group: &FxHashMap<IVec2, IVec2>, mut pos: IVec2| {
+        while let Some(&parent) = group.get(&pos) {
+            pos = parent;
+        }
+        pos
+    };
+    let mut cells = (0..width)
+        .cartesian_product(0..height)
+        .map(|(x, y)| ivec2(x, y) * 2)
+        .collect::<FxHashSet<_>>();
+    for (from, to) in joins {
+        let (from_group, to_group) = (find(&group, from), find(&group, to));
+        if from_group != to_group {
+            group.insert(from_group, to_group);
+            cells.insert(from + to);
+        }
+    }
+
+    // each cell side with nothing beyond it, going clockwise round the cell. nodes are
+    // always filled and between two nodes never is, so no corner is passed twice
+    let sides = [
+        (ivec2(0, -1), ivec2(0, 0), ivec2(1, 0)),
+        (ivec2(1, 0), ivec2(1, 0), ivec2(1, 1)),
+        (ivec2(0, 1), ivec2(1, 1), ivec2(0, 1)),
+        (ivec2(-1, 0), ivec2(0, 1), ivec2(0, 0)),
+    ];
+    let cells = &cells;
+    let next = cells
+        .iter()
+        .flat_map(|&cell| {
+            sides
+                .iter()
+                .filter(move |(beyond, _, _)| !cells.contains(&(cell + *beyond)))
+                .map(move |(_, from, to)| (cell + *from, cell + *to))
+        })
+        .collect::<FxHashMap<_, _>>();
+    let start = ivec2(0, 0);
+    let mut corners = vec![start];
+    let mut corner = next[&start];
+    while corner != start {
+        corners.push(corner);
+        corner = next[&corner];
+    }
+    corners
+}
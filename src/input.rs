@@ -0,0 +1,147 @@
+use std::{fmt, ops::Range, str::FromStr};
+
+// a problem with the input, pointing at where it is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    // both from 1, the column in the trimmed line
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// a trimmed line of input and its number, for making errors that point into it
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(index, text)| Line {
+        number: index + 1,
+        text: text.trim(),
+    })
+}
+
+pub fn non_empty_lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    lines(input).filter(|line| !line.text.is_empty())
+}
+
+// for input that stops too soon, pointing just past the end
+pub fn unexpected_end(input: &str, message: impl fmt::Display) -> ParseError {
+    ParseError {
+        line: input.lines().count() + 1,
+        column: 1,
+        text: String::new(),
+        message: message.to_string(),
+    }
+}
+
+impl<'a> Line<'a> {
+    // where at starts in the line, when it's a slice of it
+    fn offset(&self, at: &str) -> usize {
+        let offset = (at.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        if offset <= self.text.len() {
+            offset
+        } else {
+            0
+        }
+    }
+
+    pub fn error(&self, at: &str, message: impl fmt::Display) -> ParseError {
+        self.error_at(self.offset(at), message)
+    }
+
+    pub fn error_at(&self, offset: usize, message: impl fmt::Display) -> ParseError {
+        ParseError {
+            line: self.number,
+            column: offset + 1,
+            text: self.text.into(),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn parse<T>(&self, at: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let at = at.trim();
+        at.parse()
+            .map_err(|err| self.error(at, format!("{} ('{}')", err, at)))
+    }
+
+    pub fn split_once(
+        &self,
+        at: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        at.split_once(delimiter)
+            .ok_or_else(|| self.error(at, format!("expected '{}'", delimiter)))
+    }
+
+    pub fn strip_prefix(&self, at: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        at.strip_prefix(prefix)
+            .ok_or_else(|| self.error(at, format!("expected '{}'", prefix)))
+    }
+
+    pub fn strip_suffix(&self, at: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+        at.strip_suffix(suffix).ok_or_else(|| {
+            self.error_at(
+                self.offset(at) + at.len().saturating_sub(suffix.len()),
+                format!("expected '{}'", suffix),
+            )
+        })
+    }
+
+    // a byte range of at, instead of panicking when it's too short
+    pub fn get(&self, at: &'a str, range: Range<usize>) -> Result<&'a str, ParseError> {
+        at.get(range.clone()).ok_or_else(|| {
+            self.error_at(
+                self.offset(at) + range.start.min(at.len()),
+                format!("expected at least {} characters", range.end),
+            )
+        })
+    }
+}
+
+// a rectangle with a row for each non-empty line, reading each character with
+// tile (none meaning it isn't a tile)
+pub fn grid<T>(input: &str, tile: impl Fn(char) -> Option<T>) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut rows: Vec<Vec<T>> = vec![];
+    for line in non_empty_lines(input) {
+        let row = line
+            .text
+            .char_indices()
+            .map(|(offset, ch)| {
+                tile(ch).ok_or_else(|| line.error_at(offset, format!("unknown tile '{}'", ch)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(first) = rows.first() {
+            if row.len() != first.len() {
+                return Err(line.error_at(
+                    0,
+                    format!("expected {} tiles, not {}", first.len(), row.len()),
+                ));
+            }
+        }
+        rows.push(row);
+    }
+    if rows.is_empty() {
+        return Err(unexpected_end(input, "expected a grid"));
+    }
+    Ok(rows)
+}
@@ -0,0 +1,134 @@
+use anyhow::Error;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+use crate::{fixtures, Checked, Output, Part, TestFailed, TestPassed};
+
+#[derive(Serialize, Deserialize)]
+pub struct PartRecord {
+    pub answer: String,
+    pub duration_us: u128,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ExampleRecord {
+    pub name: String,
+    pub part: String,
+    pub passed: bool,
+    pub got: String,
+    pub expected: String,
+    pub duration_us: u128,
+}
+
+impl ExampleRecord {
+    fn new(checked: &Checked) -> Self {
+        ExampleRecord {
+            name: checked.example.clone(),
+            part: checked.part.to_string(),
+            passed: checked.passed(),
+            got: checked.got.clone(),
+            expected: checked.expected.clone(),
+            duration_us: checked.duration.as_micros(),
+        }
+    }
+}
+
+// one day's run, as emitted by --format json/jsonl
+#[derive(Serialize, Deserialize)]
+pub struct DayRecord {
+    pub year: u32,
+    pub day: u32,
+    pub part_a: Option<PartRecord>,
+    pub part_b: Option<PartRecord>,
+    pub test: String,
+    pub test_duration_us: Option<u128>,
+    #[serde(default)]
+    pub examples: Vec<ExampleRecord>,
+    // what the first failing example gave and wanted
+    pub test_got: Option<String>,
+    pub test_expected: Option<String>,
+    pub solve_duration_us: Option<u128>,
+    pub input_hash: Option<String>,
+    pub error: Option<String>,
+}
+
+impl DayRecord {
+    pub fn new(day: u32, year: u32, result: &Result<Output, Error>) -> Self {
+        let mut record = DayRecord {
+            year,
+            day,
+            part_a: None,
+            part_b: None,
+            test: "skipped".into(),
+            test_duration_us: None,
+            examples: vec![],
+            test_got: None,
+            test_expected: None,
+            solve_duration_us: None,
+            input_hash: None,
+            error: None,
+        };
+        match result {
+            Ok(output) => {
+                if let Some(examples) = &output.examples {
+                    record.test = "passed".into();
+                    record.test_duration_us =
+                        output.test_duration().map(|duration| duration.as_micros());
+                    record.examples = examples.iter().map(ExampleRecord::new).collect();
+                }
+                for (part, answer, duration) in output.parts.iter() {
+                    let part_record = Some(PartRecord {
+                        answer: answer.to_string(),
+                        duration_us: duration.as_micros(),
+                    });
+                    match part {
+                        Part::A => record.part_a = part_record,
+                        Part::B => record.part_b = part_record,
+                    }
+                }
+                if !output.parts.is_empty() {
+                    record.solve_duration_us = Some(
+                        output
+                            .parts
+                            .iter()
+                            .map(|(_, _, duration)| *duration)
+                            .sum::<Duration>()
+                            .as_micros(),
+                    );
+                }
+                record.input_hash = output.input_hash.map(|hash| format!("{:016x}", hash));
+            }
+            Err(err) => {
+                if let Some(failed) = err.downcast_ref::<TestFailed>() {
+                    record.test = "failed".into();
+                    record.test_duration_us = Some(
+                        failed
+                            .checked
+                            .iter()
+                            .map(|checked| checked.duration)
+                            .sum::<Duration>()
+                            .as_micros(),
+                    );
+                    record.examples = failed.checked.iter().map(ExampleRecord::new).collect();
+                    if let Some(checked) = failed.checked.iter().find(|checked| !checked.passed()) {
+                        record.test_got = Some(checked.got.clone());
+                        record.test_expected = Some(checked.expected.clone());
+                    }
+                } else if let Some(passed) = err.downcast_ref::<TestPassed>() {
+                    record.test = "passed".into();
+                    record.test_duration_us = Some(
+                        passed
+                            .checked
+                            .iter()
+                            .map(|checked| checked.duration)
+                            .sum::<Duration>()
+                            .as_micros(),
+                    );
+                    record.examples = passed.checked.iter().map(ExampleRecord::new).collect();
+                }
+                record.error = Some(fixtures::error_message(err));
+            }
+        }
+        record
+    }
+}
@@ -0,0 +1,126 @@
+use anyhow::{Context, Error};
+use chrono::NaiveDate;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::Duration,
+};
+
+use crate::{cache::Cache, site::Site};
+
+// the site asks that private leaderboards are fetched at most every 15 minutes
+pub const REFRESH: Duration = Duration::from_secs(15 * 60);
+
+// as the site sends it
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    pub last_star_ts: i64,
+    // day, then level (1 or 2)
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u32, BTreeMap<u32, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+}
+
+// one member's place, as shown and as emitted by --format json/jsonl
+#[derive(Debug, Serialize)]
+pub struct Standing {
+    pub rank: usize,
+    pub id: u64,
+    pub name: String,
+    pub local_score: u32,
+    pub stars: u32,
+    pub days: Vec<DayStars>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayStars {
+    pub day: u32,
+    // unix timestamps
+    pub part_a_ts: Option<i64>,
+    pub part_b_ts: Option<i64>,
+    // seconds from part a to part b
+    pub delta: Option<i64>,
+}
+
+pub async fn load(site: &Site, cache: &Cache, year: u32, id: u64) -> Result<Leaderboard, Error> {
+    let data = cache
+        .fetch_page(&cache.leaderboard_path(year, id), REFRESH, || async {
+            let data = site.get_leaderboard(year, id).await?;
+            parse(&data)?;
+            Ok(data)
+        })
+        .await?;
+    parse(&data)
+}
+
+fn parse(data: &str) -> Result<Leaderboard, Error> {
+    serde_json::from_str(data).context("not a leaderboard (has the session expired?)")
+}
+
+// when a day's puzzle unlocked, midnight EST
+pub fn unlock_ts(day: u32, year: u32) -> i64 {
+    NaiveDate::from_ymd_opt(year as i32, 12, day)
+        .and_then(|date| date.and_hms_opt(5, 0, 0))
+        .map_or(0, |time| time.and_utc().timestamp())
+}
+
+// best first, by score then stars then who got their last star first
+pub fn standings(leaderboard: &Leaderboard) -> Vec<Standing> {
+    leaderboard
+        .members
+        .values()
+        .sorted_by_key(|member| {
+            (
+                std::cmp::Reverse(member.local_score),
+                std::cmp::Reverse(member.stars),
+                member.last_star_ts,
+                member.id,
+            )
+        })
+        .enumerate()
+        .map(|(index, member)| Standing {
+            rank: index + 1,
+            id: member.id,
+            name: member
+                .name
+                .clone()
+                .unwrap_or_else(|| format!("(anonymous user #{})", member.id)),
+            local_score: member.local_score,
+            stars: member.stars,
+            days: member
+                .completion_day_level
+                .iter()
+                .map(|(&day, levels)| {
+                    let part_a_ts = levels.get(&1).map(|star| star.get_star_ts);
+                    let part_b_ts = levels.get(&2).map(|star| star.get_star_ts);
+                    DayStars {
+                        day,
+                        part_a_ts,
+                        part_b_ts,
+                        delta: part_a_ts.zip(part_b_ts).map(|(a, b)| b - a),
+                    }
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+// hours can go past 24 for stars got days later
+pub fn clock(secs: i64) -> String {
+    let secs = secs.max(0);
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
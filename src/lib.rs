@@ -0,0 +1,255 @@
+use anyhow::{anyhow, Error};
+use clap::ValueEnum;
+use fixtures::Example;
+use inventory::collect;
+use itertools::Itertools;
+use std::{fmt, str::FromStr};
+
+pub mod answers;
+pub mod cache;
+pub mod fixtures;
+pub mod gen;
+pub mod input;
+pub mod utils;
+pub mod verdicts;
+
+// a day's solution, registered with add_day!
+pub struct Solution {
+    pub year: u32,
+    pub day: u32,
+    pub part_a: fn(&str) -> Result<Answer, Error>,
+    pub part_b: fn(&str) -> Result<Answer, Error>,
+    // checked before solving the real input
+    pub examples: fn() -> Result<Vec<Example>, Error>,
+    // like part_a or part_b, but using the example's params
+    pub solve_example: fn(Part, &Example) -> Result<Answer, Error>,
+    // a random input of about the given size
+    pub generate: fn(&mut fastrand::Rng, usize) -> String,
+}
+
+impl Solution {
+    pub fn part(&self, part: Part) -> fn(&str) -> Result<Answer, Error> {
+        match part {
+            Part::A => self.part_a,
+            Part::B => self.part_b,
+        }
+    }
+}
+
+// a slow but simple way to solve a day, for checking the shortcuts its solution
+// takes, registered with add_reference!
+pub struct Reference {
+    pub year: u32,
+    pub day: u32,
+    // like solve_example, for any input
+    pub solve: fn(Part, &Example) -> Result<Answer, Error>,
+}
+
+// a day with params in its examples also passes params, and has a solve_example
+macro_rules! add_day {
+    ($year: literal, $num: literal, $module: tt) => {
+        pub mod $module;
+        inventory::submit! {
+            $crate::Solution {
+                year: $year,
+                day: $num,
+                part_a: |input| Ok($module::part_a(input)?.into()),
+                part_b: |input| Ok($module::part_b(input)?.into()),
+                examples: $module::examples,
+                generate: $module::generate,
+                solve_example: |part, example| {
+                    if let Some((name, _)) = example.params.first() {
+                        anyhow::bail!("day {} has no params, but this sets {}", $num, name);
+                    }
+                    match part {
+                        $crate::Part::A => Ok($module::part_a(&example.input)?.into()),
+                        $crate::Part::B => Ok($module::part_b(&example.input)?.into()),
+                    }
+                },
+            }
+        }
+    };
+    ($year: literal, $num: literal, $module: tt, params) => {
+        pub mod $module;
+        inventory::submit! {
+            $crate::Solution {
+                year: $year,
+                day: $num,
+                part_a: |input| Ok($module::part_a(input)?.into()),
+                part_b: |input| Ok($module::part_b(input)?.into()),
+                examples: $module::examples,
+                solve_example: $module::solve_example,
+                generate: $module::generate,
+            }
+        }
+    };
+}
+
+// after the day's add_day!, which declares the module
+macro_rules! add_reference {
+    ($year: literal, $num: literal, $module: tt) => {
+        inventory::submit! {
+            $crate::Reference {
+                year: $year,
+                day: $num,
+                solve: |part, example| Ok($module::reference(part, example)?.into()),
+            }
+        }
+    };
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Num(i128),
+    Text(String),
+}
+
+macro_rules! answer_from {
+    ($($num: ty),*) => {
+        $(
+            impl From<$num> for Answer {
+                fn from(value: $num) -> Self {
+                    Answer::Num(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(u32, u64, usize, i32, i64, i128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Num(num) => write!(f, "{}", num),
+            Answer::Text(text) => f.write_str(text),
+        }
+    }
+}
+
+// new days are made from template/day.rs by the new command
+pub mod y2023;
+
+collect!(Solution);
+collect!(Reference);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+    pub fn index(&self) -> usize {
+        match self {
+            Part::A => 0,
+            Part::B => 1,
+        }
+    }
+    // what the site calls the part when posting answers
+    pub fn level(&self) -> &'static str {
+        match self {
+            Part::A => "1",
+            Part::B => "2",
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Part::A => "a",
+            Part::B => "b",
+        })
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" => Ok(Part::A),
+            "b" => Ok(Part::B),
+            _ => Err(anyhow!("unknown part '{}'", s)),
+        }
+    }
+}
+
+// what day 25's part_b gives, as there's only the button to push once every
+// other star is in. running both parts skips it rather than failing the day
+#[derive(Debug)]
+pub struct NoPartB;
+
+impl fmt::Display for NoPartB {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("day 25 has no part b")
+    }
+}
+
+impl std::error::Error for NoPartB {}
+
+// both answers for an input, without part b on day 25
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part_a: Answer,
+    pub part_b: Option<Answer>,
+}
+
+// run both parts of a registered day on an input
+pub fn solve(year: u32, day: u32, input: &str) -> Result<Answers, Error> {
+    let solution = get_solution(year, day)?;
+    Ok(Answers {
+        part_a: (solution.part_a)(input)?,
+        part_b: match (solution.part_b)(input) {
+            Err(err) if err.is::<NoPartB>() => None,
+            answer => Some(answer?),
+        },
+    })
+}
+
+pub fn get_solution(year: u32, day: u32) -> Result<&'static Solution, Error> {
+    for solution in inventory::iter::<Solution> {
+        if solution.year == year && solution.day == day {
+            return Ok(solution);
+        }
+    }
+    Err(anyhow!("not implemented"))
+}
+
+pub fn get_reference(year: u32, day: u32) -> Result<&'static Reference, Error> {
+    inventory::iter::<Reference>
+        .into_iter()
+        .find(|reference| reference.year == year && reference.day == day)
+        .ok_or_else(|| {
+            let days = inventory::iter::<Reference>
+                .into_iter()
+                .filter(|reference| reference.year == year)
+                .map(|reference| reference.day)
+                .sorted()
+                .join(", ");
+            match days.is_empty() {
+                true => anyhow!("no reference solver, no day in {} has one", year),
+                false => anyhow!("no reference solver, only days {} have one", days),
+            }
+        })
+}
+
+// every year with at least one solution, oldest first
+pub fn get_years() -> Vec<u32> {
+    inventory::iter::<Solution>
+        .into_iter()
+        .map(|solution| solution.year)
+        .sorted()
+        .dedup()
+        .collect()
+}
+
+pub fn get_latest_year() -> u32 {
+    get_years().last().copied().unwrap_or(2023)
+}
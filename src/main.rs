@@ -1,109 +1,242 @@
-use anyhow::{anyhow, Error};
+use advent_2023::{
+    answers::{self, AnswerStore, Check, Source},
+    cache::{self, Cache},
+    fixtures::{self, Checked, Example, TestFailed, TestPassed},
+    gen, get_latest_year, get_reference, get_solution, get_years,
+    input::ParseError,
+    verdicts, Answer, NoPartB, Part, Reference, Solution,
+};
+use anyhow::{anyhow, bail, Context, Error};
 use chrono::{Datelike, FixedOffset, Utc};
-use clap::Parser;
-use inventory::{collect, submit};
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+use colour::{Colour, NoColour};
+use config::Config;
+use cpu_time::ThreadTime;
 use itertools::Itertools;
-use reqwest::{Client, Method};
+use serde::Deserialize;
+use site::Site;
 use std::{
+    cmp::Ordering,
+    collections::BTreeMap,
     fs::{create_dir_all, read_to_string, write},
+    io::Read,
     path::PathBuf,
+    sync::Arc,
     time::{Duration, Instant},
 };
-use tracing_subscriber::layer::SubscriberExt;
-
-mod utils;
-
-struct Solution {
-    day: u32,
-    solve: fn(&str) -> String,
-    test: fn() -> (String, String),
-}
-
-macro_rules! add_day {
-    ($num: literal, $module: tt) => {
-        mod $module;
-        submit! {
-            Solution {
-                day: $num,
-                solve: $module::solve,
-                test: $module::test,
-            }
-        }
-    };
-}
-
-/*
-// Template
-
-use itertools::Itertools;
+use supervisor::supervise;
+use tokio::{
+    sync::Semaphore,
+    task::{JoinError, JoinHandle},
+};
+use tracing_subscriber::{fmt::writer::BoxMakeWriter, layer::SubscriberExt};
 
-#[tracing::instrument(skip(input), fields(day=XXX))]
-pub fn solve(input: &str) -> String {
-    let lines = input.lines().map(|line| line.trim()).collect_vec();
-    tracing::debug!("input {:?}", lines);
-    format!("{}", 0)
-}
+mod bench;
+mod colour;
+mod config;
+mod diff;
+mod json;
+mod leaderboard;
+mod puzzle;
+mod report;
+mod scaffold;
+mod site;
+mod stats;
+mod submit;
+mod supervisor;
+mod watch;
 
-#[tracing::instrument]
-pub fn test() -> (String, String) {
-    (
-        solve("TEST_DATA"),
-        "?".into(),
-    )
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Format {
+    Text,
+    /// One array of results
+    Json,
+    /// One result per line
+    Jsonl,
 }
-*/
-
-add_day!(1, day1);
-add_day!(2, day2);
-add_day!(3, day3);
-add_day!(4, day4);
-add_day!(5, day5);
-add_day!(6, day6);
-add_day!(7, day7);
-add_day!(8, day8);
-add_day!(9, day9);
-add_day!(10, day10);
-add_day!(11, day11);
-add_day!(12, day12);
-add_day!(13, day13);
-add_day!(14, day14);
-add_day!(15, day15);
-add_day!(16, day16);
-add_day!(17, day17);
-add_day!(18, day18);
-add_day!(19, day19);
-add_day!(20, day20);
-add_day!(21, day21);
-add_day!(22, day22);
-add_day!(23, day23);
-add_day!(24, day24);
-
-collect!(Solution);
 
 #[derive(Parser, Debug)]
 struct Args {
-    #[arg(short, long)]
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[arg(short, long, global = true)]
     day: Option<u32>,
-    #[arg(long, default_value = "2023")]
-    year: u32,
+    /// Defaults to the latest year with solutions, or every year for --all and --verify
+    #[arg(long, env = "AOC_YEAR", global = true)]
+    year: Option<u32>,
     #[arg(long)]
     all: bool,
     #[arg(long)]
     timed: bool,
+    #[arg(
+        long,
+        value_enum,
+        env = "AOC_FORMAT",
+        default_value_t = Format::Text,
+        global = true
+    )]
+    format: Format,
+    #[arg(long, value_enum, env = "AOC_COLOUR", default_value_t = Colour::Auto, global = true)]
+    colour: Colour,
     #[arg(long)]
     debug: bool,
+    /// Write a chrome trace, next to the --report if there is one
     #[arg(long)]
     trace: bool,
     #[arg(long)]
     test_only: bool,
+    /// Only run one part
     #[arg(long)]
+    part: Option<Part>,
+    /// Solve with the day's slow but simple reference solver, the one diff checks
+    /// the solution against (only a few days have one)
+    #[arg(long, conflicts_with_all = ["all", "verify", "bench", "record"])]
+    reference: bool,
+    #[arg(long, global = true)]
     no_test: bool,
+    /// Save answers from this run as the expected ones
+    #[arg(long)]
+    record: bool,
+    /// Check every day with cached input against its expected answers
+    #[arg(long)]
+    verify: bool,
+    /// Days to run at once for --all, --verify and stats, 1 to time each day on its own
+    /// (defaults to the number of CPUs). A day that hits --timeout keeps running in the
+    /// background, so the days after it may share a CPU with it
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..), global = true)]
+    jobs: Option<u64>,
+    /// Time each part over this many runs, after warming up
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    bench: Option<u64>,
+    /// Where --bench results are compared to and saved (defaults to {cache}/{year}/bench.json)
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+    /// Save --bench results to the baseline
+    #[arg(long)]
+    save_baseline: bool,
+    /// Percentage a median can be slower than the baseline before it's a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+    /// Seconds to let a day's test or solve run before giving up on it
+    #[arg(long, global = true)]
+    timeout: Option<f64>,
+    #[arg(long, default_value = "https://adventofcode.com", global = true)]
+    base_url: String,
+    /// Never talk to the site, only use cached or given input
+    #[arg(long, global = true)]
+    offline: bool,
+    /// Solve this file (or - for stdin) instead of the day's input, so its answers
+    /// can't be recorded, submitted or shown in stats as the day's
+    #[arg(long, conflicts_with_all = ["all", "verify", "record"])]
+    input: Option<PathBuf>,
+    /// Also write an HTML report of an --all run, with a chart of each day's time
+    #[arg(long, requires = "all")]
+    report: Option<PathBuf>,
+    /// Rebuild and rerun the day whenever its source, fixture or input changes
+    #[arg(long, conflicts_with_all = ["all", "verify", "bench"])]
+    watch: bool,
+    /// Where inputs are cached
+    #[arg(long, env = "AOC_CACHE_DIR", default_value = "cache", global = true)]
+    cache_dir: PathBuf,
+    /// File holding the session cookie for the site
+    #[arg(
+        long,
+        env = "AOC_SESSION_FILE",
+        default_value = "session.txt",
+        global = true
+    )]
+    session_file: PathBuf,
+    /// Sent with every request to the site, so they know who to contact
+    #[arg(long, env = "AOC_USER_AGENT", global = true)]
+    user_agent: Option<String>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Submit an answer, working it out from the solution if not given
+    Submit { part: Part, answer: Option<String> },
+    /// Save the puzzle as markdown in the cache, and its examples as a fixture
+    FetchPuzzle,
+    /// Start a new day from the template
+    New {
+        /// Fetch the puzzle and its examples too
+        #[arg(long)]
+        fetch: bool,
+    },
+    /// Look after the cached inputs, for --day or every day
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+    /// Show a private leaderboard's standings and star times, for --day or every day
+    Leaderboard {
+        #[arg(long)]
+        id: u64,
+    },
+    /// Show stars, time to solve and runtime for each released day (or --day), with the
+    /// site's personal stats
+    Stats,
+    /// Check --day's solution gives the same answers as its reference solver, on
+    /// generated inputs or the cached one, showing the smallest input they differ on.
+    /// Only a few days have a reference solver
+    Diff {
+        /// The first seed to generate from
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// How many inputs to generate
+        #[arg(long, default_value_t = 100)]
+        count: u64,
+        #[arg(long, default_value_t = 6)]
+        size: usize,
+        /// Use the day's input (or --input) instead of generating them
+        #[arg(long)]
+        cached: bool,
+        /// Only check one part
+        #[arg(long)]
+        part: Option<Part>,
+        /// Passed to both solvers as an example's param would be, e.g. steps=500
+        #[arg(long, value_parser = parse_param)]
+        param: Vec<(String, i64)>,
+    },
+    /// Print a random input for --day, the same each time for a seed
+    Gen {
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Roughly how many lines, or how wide a map is
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum CacheAction {
+    /// Show each cached input and when it was fetched
+    List,
+    /// Check cached inputs are puzzle inputs that haven't changed since being fetched
+    Verify,
+    /// Remove cached inputs, keeping verdicts and bench results
+    Clear,
+    /// Fetch every released day that isn't cached yet
+    Prefetch {
+        /// Seconds to wait between requests
+        #[arg(long, default_value_t = 5.0)]
+        delay: f64,
+    },
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Error> {
-    let args = Args::parse();
+    // aoc.toml files fill in anything not given as a flag or environment variable
+    let matches = Args::command().get_matches();
+    let mut args = Args::from_arg_matches(&matches)?;
+    Config::load()?.apply(&mut args, &matches);
 
+    let colour = if args.format == Format::Text {
+        args.colour.enabled(&std::io::stdout())
+    } else {
+        args.colour.enabled(&std::io::stderr())
+    };
     let subscriber = tracing_subscriber::FmtSubscriber::builder()
         .with_max_level(if args.debug {
             tracing::Level::DEBUG
@@ -112,10 +245,28 @@ async fn main() -> Result<(), Error> {
         })
         .with_target(false)
         .without_time()
+        .with_ansi(colour)
+        .with_writer(match (args.format == Format::Text, colour) {
+            (true, true) => BoxMakeWriter::new(std::io::stdout),
+            (true, false) => BoxMakeWriter::new(|| NoColour(std::io::stdout())),
+            (false, true) => BoxMakeWriter::new(std::io::stderr),
+            (false, false) => BoxMakeWriter::new(|| NoColour(std::io::stderr())),
+        })
         .finish();
 
+    let trace_path = match (&args.report, args.trace) {
+        (Some(report), true) => Some(report::trace_path(report)),
+        _ => None,
+    };
     let _guard = if args.trace {
-        let (chrome_layer, guard) = tracing_chrome::ChromeLayerBuilder::new().build();
+        let mut builder = tracing_chrome::ChromeLayerBuilder::new();
+        if let Some(path) = &trace_path {
+            if let Some(parent) = path.parent() {
+                create_dir_all(parent)?;
+            }
+            builder = builder.file(path);
+        }
+        let (chrome_layer, guard) = builder.build();
         tracing::subscriber::set_global_default(subscriber.with(chrome_layer))?;
         Some(guard)
     } else {
@@ -123,36 +274,313 @@ async fn main() -> Result<(), Error> {
         None
     };
 
-    if args.all {
-        let start = Instant::now();
-        let tasks = (0..25)
-            .map(|day| tokio::spawn(run(1 + day, args.year, args.test_only, args.no_test)))
-            .collect_vec();
-        let mut outputs = vec![];
-        for task in tasks {
-            outputs.push(task.await.unwrap())
+    supervisor::install_panic_hook();
+    let site = Site::new(
+        &args.base_url,
+        args.offline,
+        &args.session_file,
+        args.user_agent.as_deref(),
+    );
+    let cache = Cache::new(&args.cache_dir);
+    if args.watch
+        && args
+            .input
+            .as_ref()
+            .is_some_and(|input| input.as_os_str() == "-")
+    {
+        bail!("--watch can't rerun with input from stdin");
+    }
+    if args.input.is_some() && matches!(args.command, Some(Command::Submit { .. } | Command::Stats))
+    {
+        bail!("--input isn't the day's input, so can't be submitted or shown in stats");
+    }
+    let inputs = InputSource {
+        site: site.clone(),
+        cache: cache.clone(),
+        given: read_given_input(&args)?,
+    };
+    let year = args.year.unwrap_or_else(get_latest_year);
+    let years = match args.year {
+        Some(year) => vec![year],
+        None => get_years(),
+    };
+
+    match (&args.command, args.bench) {
+        (Some(Command::Submit { part, answer }), _) => {
+            let day = day_or_today(&args)?;
+            let answer = match answer {
+                Some(answer) => answer.clone(),
+                None => {
+                    let options = RunOptions {
+                        test_only: false,
+                        part: Some(*part),
+                        ..(&args).into()
+                    };
+                    get_answer(day, year, *part, options, &inputs).await?
+                }
+            };
+            write_submission(
+                day,
+                *part,
+                submit::submit(&site, &cache, day, year, *part, &answer).await,
+            )?;
         }
-        for (index, output) in outputs.into_iter().enumerate() {
-            write_output(1 + index as u32, output, &args);
+        (Some(Command::FetchPuzzle), _) => {
+            let day = day_or_today(&args)?;
+            fetch_puzzle(day, year, &site, &cache).await?;
         }
-        let duration = Instant::now() - start;
-        if args.timed {
-            tracing::info!(
-                "\x1b[93mCompleted in: {}\x1b[0m",
-                short_duration_to_str(duration)
+        (Some(Command::New { fetch }), _) => {
+            let day = day_or_today(&args)?;
+            let path = scaffold::new_day(day, year)?;
+            tracing::info!("created {}, rebuild to run it", path.display());
+            if *fetch {
+                fetch_puzzle(day, year, &site, &cache).await?;
+            }
+        }
+        (Some(Command::Cache { action }), _) => {
+            manage_cache(action, &args, year, &inputs).await?;
+        }
+        (Some(Command::Leaderboard { id }), _) => {
+            let standings =
+                leaderboard::standings(&leaderboard::load(&site, &cache, year, *id).await?);
+            write_leaderboard(standings, year, &args)?;
+        }
+        (
+            Some(Command::Diff {
+                seed,
+                count,
+                size,
+                cached,
+                part,
+                param,
+            }),
+            _,
+        ) => {
+            let day = day_or_today(&args)?;
+            let checker = diff::Checker::new(
+                year,
+                day,
+                param.clone(),
+                args.timeout.map(Duration::from_secs_f64),
+            )?;
+            let parts = match part {
+                Some(part) => vec![*part],
+                None => vec![Part::A, Part::B],
+            };
+            let inputs = if *cached {
+                vec![(
+                    "the cached input".to_string(),
+                    get_data(day, year, &inputs).await?,
+                )]
+            } else {
+                (*seed..seed + count)
+                    .map(|seed| {
+                        Ok((
+                            format!("seed {seed}"),
+                            gen::generate(year, day, seed, *size)?,
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?
+            };
+            let mut found = None;
+            let (mut compared, mut skipped) = (0, 0);
+            'inputs: for (name, input) in inputs.iter() {
+                for &part in parts.iter() {
+                    match checker.check(part, input).await {
+                        diff::Comparison::Agreed => compared += 1,
+                        diff::Comparison::Disagreed(disagreement) => {
+                            found = Some((name, disagreement));
+                            break 'inputs;
+                        }
+                        diff::Comparison::Skipped(err) => {
+                            skipped += 1;
+                            tracing::info!(
+                                "{}\x1b[93mskipped {name} part {part}, the reference couldn't answer: {:#}\x1b[0m",
+                                day_prefix(day),
+                                err
+                            );
+                        }
+                    }
+                }
+            }
+            let skipped = match skipped {
+                0 => String::default(),
+                _ => format!(", skipping {skipped} the reference couldn't answer"),
+            };
+            match found {
+                Some((name, disagreement)) => {
+                    write_disagreement(day, name, &checker.minimise(disagreement).await);
+                    bail!("the solution and its reference disagree");
+                }
+                None if compared == 0 => {
+                    bail!("the reference couldn't answer anything, so nothing was compared")
+                }
+                None if *cached => {
+                    tracing::info!("{}agreed on the cached input{skipped}", day_prefix(day))
+                }
+                None => tracing::info!(
+                    "{}agreed on {compared} parts of {count} generated inputs{skipped}",
+                    day_prefix(day)
+                ),
+            }
+        }
+        (Some(Command::Gen { seed, size }), _) => {
+            let day = day_or_today(&args)?;
+            print!("{}", gen::generate(year, day, *seed, *size)?);
+        }
+        (Some(Command::Stats), _) => {
+            let site_stats = match stats::load(&site, &cache, year).await {
+                Ok(site_stats) => site_stats,
+                Err(err) => {
+                    tracing::error!("{:#}, so only using local data", err);
+                    stats::SiteStats::new()
+                }
+            };
+            let baseline = bench::load_baseline(&cache.bench_path(year))?;
+            let days = match args.day {
+                Some(day) => vec![day],
+                None => get_released_days(year),
+            };
+            // only days whose input is already cached are run, so this never fetches inputs
+            let runnable = days.iter().filter(|&&day| {
+                get_solution(year, day).is_ok() && cache.input_path(day, year).exists()
+            });
+            let options = RunOptions {
+                test_only: false,
+                no_test: true,
+                part: None,
+                ..(&args).into()
+            };
+            let mut tasks = spawn_runs(
+                runnable.map(|&day| (year, day)),
+                options,
+                &inputs,
+                jobs(&args),
+            )
+            .into_iter()
+            .map(|(_, day, task)| (day, task))
+            .collect::<BTreeMap<_, _>>();
+            let mut rows = vec![];
+            for day in days {
+                let solution = get_solution(year, day).is_ok();
+                let run = match tasks.remove(&day) {
+                    Some(task) => Some(flatten_join(task.await)),
+                    None => None,
+                };
+                rows.push(stats::DayRow::new(
+                    day,
+                    year,
+                    solution,
+                    site_stats.get(&day),
+                    (
+                        &verdicts::VerdictLog::load(&cache, day, year)?,
+                        &AnswerStore::load(day, year)?,
+                        baseline.get(&day),
+                    ),
+                    run.as_ref(),
+                ));
+            }
+            write_stats(&rows, args.format)?;
+        }
+        (None, _) if args.verify => {
+            let days = years
+                .iter()
+                .cartesian_product(1..=25)
+                .filter(|&(&year, day)| {
+                    get_solution(year, day).is_ok() && cache.input_path(day, year).exists()
+                })
+                .map(|(&year, day)| (year, day));
+            let options = RunOptions {
+                test_only: false,
+                ..(&args).into()
+            };
+            let tasks = spawn_runs(days, options, &inputs, jobs(&args));
+            let mut failures = 0;
+            for (year, day, task) in tasks {
+                failures += write_verify(day, year, args.part, flatten_join(task.await));
+            }
+            if failures > 0 {
+                bail!("{} parts did not match their expected answers", failures);
+            }
+        }
+        (None, Some(runs)) => {
+            let days = if args.all {
+                (1..=25)
+                    .filter(|&day| get_solution(year, day).is_ok())
+                    .collect_vec()
+            } else {
+                vec![day_or_today(&args)?]
+            };
+            let baseline_path = args
+                .baseline
+                .clone()
+                .unwrap_or_else(|| cache.bench_path(year));
+            let mut baseline = bench::load_baseline(&baseline_path)?;
+            let mut regressions = 0;
+            // one day at a time so they don't compete for cpu
+            for day in days {
+                let result = bench_day(day, year, runs as usize, &args, &inputs).await;
+                regressions += write_bench(day, &result, baseline.get(&day), args.threshold);
+                // keeping the timings of any part that wasn't run
+                if let (true, Ok(stats)) = (args.save_baseline, result) {
+                    baseline.entry(day).or_default().extend(stats);
+                }
+            }
+            if args.save_baseline {
+                bench::save_baseline(&baseline_path, &baseline)?;
+            }
+            if regressions > 0 {
+                bail!("{} timings regressed against the baseline", regressions);
+            }
+        }
+        (None, None) if args.watch => {
+            let day = day_or_today(&args)?;
+            let mut paths = vec![
+                format!("src/y{year}/day{day}.rs").into(),
+                fixtures::path(day, year),
+                cache.input_path(day, year),
+            ];
+            paths.extend(args.input.clone());
+            watch::watch(day, year, &args, paths).await?;
+        }
+        (None, None) if args.all => {
+            let start = Instant::now();
+            let days = years.iter().cartesian_product(1..=25);
+            let jobs = jobs(&args);
+            let tasks = spawn_runs(
+                days.map(|(&year, day)| (year, day)),
+                (&args).into(),
+                &inputs,
+                jobs,
             );
+            let mut outputs = vec![];
+            for (year, day, task) in tasks {
+                outputs.push((year, day, flatten_join(task.await)))
+            }
+            let duration = Instant::now() - start;
+            if let Some(report) = &args.report {
+                report::write_report(report, &outputs, duration, trace_path.as_deref())?;
+            }
+            let cpu_total = outputs
+                .iter()
+                .filter_map(|(_, _, output)| output.as_ref().ok())
+                .map(|output| output.cpu_duration)
+                .sum::<Duration>();
+            write_outputs(outputs, &args)?;
+            if args.timed && args.format == Format::Text {
+                tracing::info!(
+                    "\x1b[93mCompleted in: {} (days took {} of cpu time, {} at a time)\x1b[0m",
+                    short_duration_to_str(duration),
+                    short_duration_to_str(cpu_total),
+                    jobs
+                );
+            }
+        }
+        (None, None) => {
+            let day = day_or_today(&args)?;
+            let output = run(day, year, (&args).into(), inputs).await;
+            write_outputs(vec![(year, day, output)], &args)?;
         }
-    } else {
-        let day = if let Some(day) = args.day {
-            day
-        } else {
-            get_today()?
-        };
-        write_output(
-            day,
-            run(day, args.year, args.test_only, args.no_test).await,
-            &args,
-        );
     }
     Ok(())
 }
@@ -165,91 +593,826 @@ fn short_duration_to_str(duration: Duration) -> String {
     }
 }
 
-fn write_output(day: u32, result: Result<(String, Duration), Error>, args: &Args) {
-    let prefix = format!(
-        "\x1b[34mDay {day}{} \x1b[0m",
-        if day < 10 { " " } else { "" }
-    );
+// (year, day, output) for each day run
+fn write_outputs(
+    outputs: Vec<(u32, u32, Result<Output, Error>)>,
+    args: &Args,
+) -> Result<(), Error> {
+    if args.record {
+        for (year, day, output) in outputs.iter() {
+            if let Ok(output) = output {
+                record_answers(*day, *year, &output.parts)?;
+            }
+        }
+    }
+    match args.format {
+        Format::Text => {
+            let many_years = outputs
+                .iter()
+                .map(|(year, _, _)| year)
+                .all_equal_value()
+                .is_err();
+            let mut last_year = None;
+            for (year, day, output) in outputs {
+                if many_years && last_year != Some(year) {
+                    tracing::info!("\x1b[93m{}\x1b[0m", year);
+                    last_year = Some(year);
+                }
+                write_output(day, output, args);
+            }
+        }
+        Format::Json => {
+            let records = outputs
+                .iter()
+                .map(|(year, day, output)| json::DayRecord::new(*day, *year, output))
+                .collect_vec();
+            println!("{}", serde_json::to_string_pretty(&records)?);
+        }
+        Format::Jsonl => {
+            for (year, day, output) in outputs.iter() {
+                let record = json::DayRecord::new(*day, *year, output);
+                println!("{}", serde_json::to_string(&record)?);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_output(day: u32, result: Result<Output, Error>, args: &Args) {
+    let prefix = day_prefix(day);
     match result {
-        Ok((result, duration)) => tracing::info!(
+        Ok(Output {
+            examples: Some(examples),
+            parts,
+            ..
+        }) if parts.is_empty() => tracing::info!(
+            "{}passed {}",
+            prefix,
+            examples
+                .iter()
+                .map(|checked| {
+                    let timing = if args.timed {
+                        format!(
+                            "\x1b[93m ({})\x1b[0m",
+                            short_duration_to_str(checked.duration)
+                        )
+                    } else {
+                        String::default()
+                    };
+                    format!("{} {}{}", checked.example, checked.part, timing)
+                })
+                .join(", ")
+        ),
+        Ok(Output { parts, .. }) => tracing::info!(
             "{}{}{}",
             prefix,
-            result,
+            parts.iter().map(|(_, answer, _)| answer).join("/"),
             if args.timed {
-                format!("\x1b[93m ({})\x1b[0m", short_duration_to_str(duration))
+                format!(
+                    "\x1b[93m ({})\x1b[0m",
+                    parts
+                        .iter()
+                        .map(|(part, _, duration)| format!(
+                            "{part} {}",
+                            short_duration_to_str(*duration)
+                        ))
+                        .join(", ")
+                )
             } else {
                 String::default()
             }
         ),
-        Err(err) => tracing::error!("{}{}", prefix, err),
+        Err(err) => write_error(&prefix, &err),
     }
 }
 
-fn get_cache_path(day: u32, year: u32) -> PathBuf {
-    format!("cache/{year}/day{day}.tmp").into()
+// in red, with the line and a marker under the column if it's from bad input
+fn write_error(prefix: &str, err: &Error) {
+    tracing::error!("{}\x1b[31m{}\x1b[0m", prefix, fixtures::error_message(err));
+    if let Some(parse_error) = err.downcast_ref::<ParseError>() {
+        tracing::error!("{}    {}", prefix, parse_error.text);
+        tracing::error!(
+            "{}    \x1b[31m{}^\x1b[0m",
+            prefix,
+            " ".repeat(parse_error.column - 1)
+        );
+    }
 }
 
-async fn get_data(day: u32, year: u32) -> Result<String, Error> {
+async fn bench_day(
+    day: u32,
+    year: u32,
+    runs: usize,
+    args: &Args,
+    inputs: &InputSource,
+) -> Result<bench::DayStats, Error> {
+    let solution = get_solution(year, day)?;
+    let timeout = args.timeout.map(Duration::from_secs_f64);
+    if !args.no_test {
+        supervise(timeout, move || run_test(solution, None)).await??;
+    }
+    let data = get_data(day, year, inputs).await?;
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![Part::A, Part::B],
+    };
+    supervise(timeout, move || bench::bench(solution, &data, &parts, runs)).await?
+}
+
+// the smallest input found goes to stdout, so it can be saved and given as --input
+fn write_disagreement(day: u32, name: &str, disagreement: &diff::Disagreement) {
+    let prefix = day_prefix(day);
+    let got = match &disagreement.solution {
+        Ok(answer) => format!("'{}'", answer),
+        Err(err) => format!("an error ({})", err),
+    };
+    tracing::error!(
+        "{}part {} of {} gave {}, the reference gave '{}', down to {} lines:",
+        prefix,
+        disagreement.part,
+        name,
+        got,
+        disagreement.reference,
+        disagreement.input.lines().count()
+    );
+    print!("{}", disagreement.input);
+}
+
+// a --param for diff
+fn parse_param(param: &str) -> Result<(String, i64), String> {
+    let (name, value) = param
+        .split_once('=')
+        .ok_or_else(|| format!("expected name=value, not '{}'", param))?;
+    let value = value
+        .trim()
+        .parse()
+        .map_err(|err| format!("{} ('{}')", err, value))?;
+    Ok((name.trim().into(), value))
+}
+
+// returns the number of timings that regressed
+fn write_bench(
+    day: u32,
+    result: &Result<bench::DayStats, Error>,
+    baseline: Option<&bench::DayStats>,
+    threshold: f64,
+) -> usize {
+    let prefix = day_prefix(day);
+    match result {
+        Ok(day_stats) => {
+            let mut regressions = 0;
+            for (name, stats) in day_stats {
+                let compared = match baseline.and_then(|baseline| baseline.get(name)) {
+                    Some(base) => {
+                        let change = 100.0 * (stats.median - base.median) / base.median;
+                        let colour = if change > threshold {
+                            regressions += 1;
+                            31
+                        } else if change < -threshold {
+                            32
+                        } else {
+                            93
+                        };
+                        format!(" \x1b[{colour}m{:+.1}%\x1b[0m", change)
+                    }
+                    None => String::default(),
+                };
+                tracing::info!(
+                    "{}{:5} median {} (min {}, mean {}, p95 {}, sd {}){}",
+                    prefix,
+                    name,
+                    bench::nanos_to_str(stats.median),
+                    bench::nanos_to_str(stats.min),
+                    bench::nanos_to_str(stats.mean),
+                    bench::nanos_to_str(stats.p95),
+                    bench::nanos_to_str(stats.stddev),
+                    compared
+                );
+            }
+            regressions
+        }
+        Err(err) => {
+            write_error(&prefix, err);
+            0
+        }
+    }
+}
+
+fn record_answers(day: u32, year: u32, parts: &[(Part, Answer, Duration)]) -> Result<(), Error> {
+    let mut store = AnswerStore::load(day, year)?;
+    for (part, answer, _) in parts {
+        store.set(*part, Source::Recorded, &answer.to_string());
+    }
+    store.save()
+}
+
+// returns the number of parts that didn't match
+// only the parts that were run are checked, so --part skips the other one
+fn write_verify(day: u32, year: u32, part: Option<Part>, result: Result<Output, Error>) -> usize {
+    let prefix = day_prefix(day);
+    match result.and_then(|output| Ok((AnswerStore::load(day, year)?, output))) {
+        Ok((store, output)) => {
+            let mut failures = 0;
+            let parts = [Part::A, Part::B]
+                .into_iter()
+                .filter(|&run| part.is_none_or(|part| part == run))
+                .filter(|&part| output.answer(part).is_some() || store.get(part).is_some())
+                .map(|part| {
+                    let answer = output.answer(part).map(|answer| answer.to_string());
+                    let answer = answer.as_deref();
+                    let check = store.check(part, answer);
+                    let colour = match check {
+                        Check::Pass => 32,
+                        Check::Unknown => 93,
+                        Check::Fail | Check::Changed => {
+                            failures += 1;
+                            31
+                        }
+                    };
+                    let expected = match (check, store.get(part)) {
+                        (Check::Fail | Check::Changed, Some((_, expected))) => {
+                            format!(" (expected {})", expected)
+                        }
+                        _ => String::default(),
+                    };
+                    format!(
+                        "{part} \x1b[{colour}m{check}\x1b[0m {}{expected}",
+                        answer.unwrap_or("-")
+                    )
+                })
+                .join(", ");
+            if failures > 0 {
+                tracing::error!("{}{}", prefix, parts);
+            } else {
+                tracing::info!("{}{}", prefix, parts);
+            }
+            failures
+        }
+        Err(err) => {
+            write_error(&prefix, &err);
+            1
+        }
+    }
+}
+
+// errors are returned too, so scripts can tell the answer was never sent
+fn write_submission(
+    day: u32,
+    part: Part,
+    result: Result<verdicts::Submission, Error>,
+) -> Result<(), Error> {
+    let prefix = format!("{}\x1b[34mpart {part} \x1b[0m", day_prefix(day));
+    match result {
+        Ok(submission) => {
+            let colour = match submission.verdict {
+                verdicts::Verdict::Correct => 32,
+                verdicts::Verdict::Wait | verdicts::Verdict::WrongLevel => 93,
+                _ => 31,
+            };
+            tracing::info!(
+                "{}{} \x1b[{}m{}\x1b[0m{}",
+                prefix,
+                submission.answer,
+                colour,
+                submission.verdict,
+                if submission.cooldown > 0 {
+                    format!(" (wait {}s)", submission.cooldown)
+                } else {
+                    String::default()
+                }
+            );
+            Ok(())
+        }
+        Err(err) => {
+            tracing::error!("{}{}", prefix, err);
+            Err(err.context(format!("part {part} was not submitted")))
+        }
+    }
+}
+
+fn write_leaderboard(
+    mut standings: Vec<leaderboard::Standing>,
+    year: u32,
+    args: &Args,
+) -> Result<(), Error> {
+    if args.format != Format::Text {
+        if let Some(day) = args.day {
+            for standing in standings.iter_mut() {
+                standing.days.retain(|stars| stars.day == day);
+            }
+        }
+        if args.format == Format::Json {
+            println!("{}", serde_json::to_string_pretty(&standings)?);
+        } else {
+            for standing in standings.iter() {
+                println!("{}", serde_json::to_string(standing)?);
+            }
+        }
+        return Ok(());
+    }
+
+    // gold for both stars, grey for just the first
+    let last_day = standings
+        .iter()
+        .flat_map(|standing| standing.days.iter().map(|stars| stars.day))
+        .max()
+        .unwrap_or(0);
+    let name_width = standings
+        .iter()
+        .map(|standing| standing.name.chars().count())
+        .max()
+        .unwrap_or(0);
+    for standing in standings.iter() {
+        let stars = (1..=last_day)
+            .map(
+                |day| match standing.days.iter().find(|stars| stars.day == day) {
+                    Some(stars) if stars.part_b_ts.is_some() => "\x1b[93m*\x1b[0m",
+                    Some(_) => "\x1b[37m*\x1b[0m",
+                    None => "\x1b[90m.\x1b[0m",
+                },
+            )
+            .collect::<String>();
+        tracing::info!(
+            "{:>3}) {:>5} {} {}",
+            standing.rank,
+            standing.local_score,
+            stars,
+            standing.name
+        );
+    }
+
+    // times are from when the puzzle unlocked, in the order people finished
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => (1..=last_day).collect(),
+    };
+    for day in days {
+        let prefix = day_prefix(day);
+        let unlock = leaderboard::unlock_ts(day, year);
+        let rows = standings
+            .iter()
+            .filter_map(|standing| {
+                let stars = standing.days.iter().find(|stars| stars.day == day)?;
+                Some((standing, stars))
+            })
+            .sorted_by_key(|(_, stars)| (stars.part_b_ts.unwrap_or(i64::MAX), stars.part_a_ts))
+            .collect_vec();
+        if rows.is_empty() {
+            tracing::info!("{}no stars yet", prefix);
+        }
+        for (standing, stars) in rows {
+            let time =
+                |ts: Option<i64>| ts.map_or("-".into(), |ts| leaderboard::clock(ts - unlock));
+            tracing::info!(
+                "{}{:name_width$}  a {}  b {}{}",
+                prefix,
+                standing.name,
+                time(stars.part_a_ts),
+                time(stars.part_b_ts),
+                stars.delta.map_or(String::default(), |delta| format!(
+                    " \x1b[93m(+{})\x1b[0m",
+                    leaderboard::clock(delta)
+                ))
+            );
+        }
+    }
+    Ok(())
+}
+
+fn write_stats(rows: &[stats::DayRow], format: Format) -> Result<(), Error> {
+    match format {
+        Format::Text => (),
+        Format::Json => {
+            println!("{}", serde_json::to_string_pretty(rows)?);
+            return Ok(());
+        }
+        Format::Jsonl => {
+            for row in rows {
+                println!("{}", serde_json::to_string(row)?);
+            }
+            return Ok(());
+        }
+    }
+
+    // red for anything that needs looking at
+    for row in rows {
+        let prefix = day_prefix(row.day);
+        let stars = [&row.part_a, &row.part_b]
+            .iter()
+            .map(|part| {
+                if part.star {
+                    "\x1b[93m*\x1b[0m"
+                } else {
+                    "\x1b[90m.\x1b[0m"
+                }
+            })
+            .collect::<String>();
+        let parts = [(Part::A, &row.part_a), (Part::B, &row.part_b)]
+            .iter()
+            .map(|(part, stats)| {
+                let check = match stats.check.as_deref() {
+                    Some("pass") => "\x1b[32mpass\x1b[0m".into(),
+                    Some("unknown") => "\x1b[93munknown\x1b[0m".into(),
+                    Some(check) => format!(
+                        "\x1b[31m{check} {}\x1b[0m{}",
+                        stats.answer.as_deref().unwrap_or("-"),
+                        stats
+                            .expected
+                            .as_ref()
+                            .map_or(String::default(), |expected| format!(
+                                " (expected {expected})"
+                            ))
+                    ),
+                    None => "-".into(),
+                };
+                format!(
+                    "{part} {:>8} {:>7} {:>8} {check}",
+                    stats.time_to_solve.as_deref().unwrap_or("-"),
+                    stats.rank.map_or("-".into(), |rank| format!("#{rank}")),
+                    stats.runtime()
+                )
+            })
+            .join("  ");
+        let notes = if !row.solution {
+            " \x1b[31mno solution\x1b[0m".into()
+        } else if let Some(err) = &row.error {
+            format!(" \x1b[31m{err}\x1b[0m")
+        } else {
+            String::default()
+        };
+        if row.needs_attention() {
+            tracing::error!("{}{}  {}{}", prefix, stars, parts, notes);
+        } else {
+            tracing::info!("{}{}  {}{}", prefix, stars, parts, notes);
+        }
+    }
+    let stars = rows
+        .iter()
+        .flat_map(|row| [&row.part_a, &row.part_b])
+        .filter(|part| part.star)
+        .count();
+    tracing::info!("\x1b[93m{stars}\x1b[0m stars");
+    Ok(())
+}
+
+async fn fetch_puzzle(day: u32, year: u32, site: &Site, cache: &Cache) -> Result<(), Error> {
+    let html = site.get_puzzle(day, year).await?;
+    let path = cache.puzzle_path(day, year);
+    create_dir_all(path.parent().unwrap())?;
+    write(&path, puzzle::to_markdown(&html))?;
+    let fetched = puzzle::extract_examples(&html);
+    if fetched.is_empty() {
+        tracing::error!("no examples with answers found in {}", path.display());
+        return Ok(());
+    }
+    // an existing fixture may have been edited by hand, so it's added to rather
+    // than replaced, and one that doesn't load is left alone
+    let mut examples = match fixtures::path(day, year).exists() {
+        true => fixtures::load(day, year)?,
+        false => vec![],
+    };
+    let changed = fixtures::merge(&mut examples, fetched);
+    let fixture = fixtures::save(day, year, &examples)?;
+    tracing::info!(
+        "saved {} and {} new or updated examples to {}",
+        path.display(),
+        changed,
+        fixture.display()
+    );
+    Ok(())
+}
+
+async fn manage_cache(
+    action: &CacheAction,
+    args: &Args,
+    year: u32,
+    inputs: &InputSource,
+) -> Result<(), Error> {
+    let cache = &inputs.cache;
+    let years = match args.year {
+        Some(year) => vec![year],
+        None => cache.years(),
+    };
+    let cached = years
+        .into_iter()
+        .flat_map(|year| cache.days(year).into_iter().map(move |day| (year, day)))
+        .filter(|&(_, day)| args.day.is_none_or(|only| only == day))
+        .collect_vec();
+    let prefix = |year: u32, day: u32| format!("\x1b[34m{year} \x1b[0m{}", day_prefix(day));
+
+    match action {
+        CacheAction::List => {
+            for (year, day) in cached {
+                let data = read_to_string(cache.input_path(day, year))?;
+                let fetched = match cache.read_meta(day, year)? {
+                    Some(meta) => chrono::DateTime::from_timestamp(meta.fetched, 0)
+                        .map_or("?".into(), |time| {
+                            time.format("%Y-%m-%d %H:%M UTC").to_string()
+                        }),
+                    None => "unknown".into(),
+                };
+                tracing::info!(
+                    "{}{} bytes, {} lines, fetched {}",
+                    prefix(year, day),
+                    data.len(),
+                    data.lines().count(),
+                    fetched
+                );
+            }
+        }
+        CacheAction::Verify => {
+            let mut failures = 0;
+            for (year, day) in cached {
+                match cache.verify(day, year) {
+                    Ok(Some(meta)) => {
+                        tracing::info!("{}\x1b[32mok\x1b[0m {:016x}", prefix(year, day), meta.hash)
+                    }
+                    Ok(None) => tracing::info!("{}\x1b[93mno meta\x1b[0m", prefix(year, day)),
+                    Err(err) => {
+                        failures += 1;
+                        tracing::error!("{}{}", prefix(year, day), err)
+                    }
+                }
+            }
+            if failures > 0 {
+                bail!(
+                    "{} cached inputs are bad, clear them and fetch again",
+                    failures
+                );
+            }
+        }
+        CacheAction::Clear => {
+            for (year, day) in cached {
+                cache.clear(day, year)?;
+                tracing::info!("{}cleared", prefix(year, day));
+            }
+        }
+        CacheAction::Prefetch { delay } => {
+            let days = get_released_days(year)
+                .into_iter()
+                .filter(|&day| args.day.is_none_or(|only| only == day))
+                .filter(|&day| !cache.input_path(day, year).exists())
+                .collect_vec();
+            for (index, day) in days.into_iter().enumerate() {
+                // be polite to the site
+                if index > 0 {
+                    tokio::time::sleep(Duration::from_secs_f64(*delay)).await;
+                }
+                let data = fetch_input(day, year, inputs).await?;
+                tracing::info!("{}fetched {} bytes", prefix(year, day), data.len());
+            }
+        }
+    }
+    Ok(())
+}
+
+// where each day's input comes from
+#[derive(Clone, Debug)]
+struct InputSource {
+    site: Site,
+    cache: Cache,
+    // from --input, used in place of whatever day is run
+    given: Option<String>,
+}
+
+fn read_given_input(args: &Args) -> Result<Option<String>, Error> {
+    let Some(path) = &args.input else {
+        return Ok(None);
+    };
+    let mut data = String::new();
+    if path.as_os_str() == "-" {
+        std::io::stdin().read_to_string(&mut data)?;
+    } else {
+        data = read_to_string(path)
+            .with_context(|| format!("cannot read input {}", path.display()))?;
+    }
+    Ok(Some(data))
+}
+
+async fn get_data(day: u32, year: u32, inputs: &InputSource) -> Result<String, Error> {
+    if let Some(data) = &inputs.given {
+        return Ok(data.clone());
+    }
+
     // first check cache
-    if let Ok(data) = read_to_string(get_cache_path(day, year)) {
+    let path = inputs.cache.input_path(day, year);
+    if let Ok(data) = read_to_string(&path) {
         return Ok(data);
     }
 
-    // otherwise request, using session.txt and user-agent.txt
-    let session = read_to_string("session.txt")
-        .map_err(|_| anyhow!("cannot find session.txt (needed for downloading data)"))?;
-    let request = Client::new()
-        .request(
-            Method::GET,
-            format!("https://adventofcode.com/{year}/day/{day}/input"),
-        )
-        .header("Cookie", format!("session={}", session))
-        .header("User-Agent", include_str!("../user-agent.txt"));
-
-    let response = request.send().await?;
-    let text = response.text().await?;
-
-    // cache for next time
-    let path = get_cache_path(day, year);
-    create_dir_all(path.parent().unwrap())?;
-    write(path, text.clone())?;
+    // otherwise request
+    fetch_input(day, year, inputs).await
+}
 
+// request an input and cache it for next time
+async fn fetch_input(day: u32, year: u32, inputs: &InputSource) -> Result<String, Error> {
+    let text = inputs.site.get_input(day, year).await?;
+    inputs
+        .cache
+        .write_input(day, year, &text, Utc::now().timestamp())?;
     Ok(text)
 }
 
+#[derive(Clone, Copy, Debug)]
+struct RunOptions {
+    test_only: bool,
+    no_test: bool,
+    part: Option<Part>,
+    timeout: Option<Duration>,
+    reference: bool,
+}
+
+impl From<&Args> for RunOptions {
+    fn from(args: &Args) -> Self {
+        RunOptions {
+            test_only: args.test_only,
+            no_test: args.no_test,
+            part: args.part,
+            timeout: args.timeout.map(Duration::from_secs_f64),
+            reference: args.reference,
+        }
+    }
+}
+
+struct Output {
+    // None if the test was skipped
+    examples: Option<Vec<Checked>>,
+    // None if only testing
+    input_hash: Option<u64>,
+    parts: Vec<(Part, Answer, Duration)>,
+    // cpu time of the solver's thread, test included, which other days running at
+    // the same time don't add to
+    cpu_duration: Duration,
+}
+
+impl Output {
+    fn test_duration(&self) -> Option<Duration> {
+        self.examples
+            .as_ref()
+            .map(|examples| examples.iter().map(|checked| checked.duration).sum())
+    }
+
+    fn answer(&self, part: Part) -> Option<&Answer> {
+        self.parts
+            .iter()
+            .find(|(test_part, _, _)| *test_part == part)
+            .map(|(_, answer, _)| answer)
+    }
+}
+
 async fn run(
     day: u32,
     year: u32,
-    test_only: bool,
-    no_test: bool,
-) -> Result<(String, Duration), Error> {
+    options: RunOptions,
+    inputs: InputSource,
+) -> Result<Output, Error> {
     // find solution
-    let solution = get_solution(day)?;
+    let solution = get_solution(year, day)?;
+    let reference = match options.reference {
+        true => Some(get_reference(year, day)?),
+        false => None,
+    };
 
     // run test
-    if !no_test {
-        let test_start = Instant::now();
-        let (test_result, test_expected) = (solution.test)();
-        let test_duration = Instant::now() - test_start;
-
-        if test_result != test_expected {
-            return Err(anyhow!(
-                "failed test, got '{}' expected '{}'",
-                test_result,
-                test_expected,
-            ));
-        }
-        if test_only {
-            return Ok(("passed".into(), test_duration));
+    let mut examples = None;
+    let mut cpu_duration = Duration::ZERO;
+    if !options.no_test {
+        let (checked, test_cpu_duration) = supervise(options.timeout, move || {
+            let cpu_start = ThreadTime::now();
+            run_test(solution, reference).map(|checked| (checked, cpu_start.elapsed()))
+        })
+        .await??;
+        examples = Some(checked);
+        cpu_duration += test_cpu_duration;
+        if options.test_only {
+            return Ok(Output {
+                examples,
+                input_hash: None,
+                parts: vec![],
+                cpu_duration,
+            });
         }
     }
 
-    // get real data and run
-    let data = get_data(day, year).await?;
-    let start = Instant::now();
-    let result = (solution.solve)(&data);
-    let duration = Instant::now() - start;
-    Ok((result, duration))
+    // so --format json can still say the examples passed
+    let after_test = |err: Error| match &examples {
+        Some(checked) => err.context(TestPassed {
+            checked: checked.clone(),
+        }),
+        None => err,
+    };
+
+    // get real data and run each part
+    let data = get_data(day, year, &inputs).await.map_err(after_test)?;
+    let parts = match options.part {
+        Some(part) => vec![part],
+        None => vec![Part::A, Part::B],
+    };
+    let input_hash = Some(cache::hash_input(&data));
+    let both_parts = options.part.is_none();
+    let (parts, parts_cpu_duration) = supervise(options.timeout, move || {
+        let cpu_start = ThreadTime::now();
+        let parts = parts
+            .into_iter()
+            .filter_map(|part| {
+                let start = Instant::now();
+                let answer = match reference {
+                    Some(reference) => (reference.solve)(part, &Example::new("input", &data)),
+                    None => (solution.part(part))(&data),
+                };
+                match answer {
+                    Err(err) if both_parts && err.is::<NoPartB>() => None,
+                    answer => Some(
+                        answer
+                            .with_context(|| format!("part {part}"))
+                            .map(|answer| (part, answer, Instant::now() - start)),
+                    ),
+                }
+            })
+            .collect::<Result<_, Error>>()?;
+        Ok::<_, Error>((parts, cpu_start.elapsed()))
+    })
+    .await
+    .and_then(|result| result)
+    .map_err(after_test)?;
+    Ok(Output {
+        examples,
+        input_hash,
+        parts,
+        cpu_duration: cpu_duration + parts_cpu_duration,
+    })
+}
+
+// year, day and its run
+type RunTask = (u32, u32, JoinHandle<Result<Output, Error>>);
+
+fn jobs(args: &Args) -> usize {
+    match args.jobs {
+        Some(jobs) => jobs as usize,
+        None => std::thread::available_parallelism().map_or(1, |jobs| jobs.get()),
+    }
+}
+
+// starts every day's run, letting at most jobs of them go at once (in the order given).
+// each solver gets its own thread from supervise, this just stops them competing for cpu
+fn spawn_runs(
+    days: impl Iterator<Item = (u32, u32)>,
+    options: RunOptions,
+    inputs: &InputSource,
+    jobs: usize,
+) -> Vec<RunTask> {
+    let permits = Arc::new(Semaphore::new(jobs));
+    days.map(|(year, day)| {
+        let permits = permits.clone();
+        let inputs = inputs.clone();
+        let task = tokio::spawn(async move {
+            let _permit = permits.acquire_owned().await?;
+            run(day, year, options, inputs).await
+        });
+        (year, day, task)
+    })
+    .collect()
+}
+
+// a panic in a spawned task becomes an error for that day
+fn flatten_join<T>(result: Result<Result<T, Error>, JoinError>) -> Result<T, Error> {
+    result.unwrap_or_else(|err| Err(err.into()))
+}
+
+// the day's examples, solved by the reference instead if there is one
+fn run_test(solution: &Solution, reference: Option<&Reference>) -> Result<Vec<Checked>, Error> {
+    let solve = reference.map_or(solution.solve_example, |reference| reference.solve);
+    fixtures::check(&(solution.examples)().context("examples")?, solve)
+}
+
+// run the solution for just one part
+async fn get_answer(
+    day: u32,
+    year: u32,
+    part: Part,
+    options: RunOptions,
+    inputs: &InputSource,
+) -> Result<String, Error> {
+    match run(day, year, options, inputs.clone()).await?.answer(part) {
+        Some(answer) => Ok(answer.to_string()),
+        None => bail!("no answer for part {}", part),
+    }
+}
+
+// --day, or today's during advent
+fn day_or_today(args: &Args) -> Result<u32, Error> {
+    match args.day {
+        Some(day) => Ok(day),
+        None => get_today(),
+    }
+}
+
+// the blue "Day n" starting every line about a day, padded so they line up
+fn day_prefix(day: u32) -> String {
+    format!(
+        "\x1b[34mDay {day}{} \x1b[0m",
+        if day < 10 { " " } else { "" }
+    )
 }
 
 fn get_today() -> Result<u32, Error> {
@@ -261,11 +1424,15 @@ fn get_today() -> Result<u32, Error> {
     }
 }
 
-fn get_solution(day: u32) -> Result<&'static Solution, Error> {
-    for solution in inventory::iter::<Solution> {
-        if solution.day == day {
-            return Ok(solution);
-        }
-    }
-    Err(anyhow!("not implemented"))
+// days whose puzzles have unlocked, at midnight EST through december
+fn get_released_days(year: u32) -> Vec<u32> {
+    let now = Utc::now().with_timezone(&FixedOffset::west_opt(18000).unwrap());
+    // from 2025 there are only 12 days
+    let last = if year >= 2025 { 12 } else { 25 };
+    let released = match (Datelike::year(&now) as u32).cmp(&year) {
+        Ordering::Greater => last,
+        Ordering::Equal if Datelike::month(&now) == 12 => Datelike::day(&now).min(last),
+        _ => 0,
+    };
+    (1..=released).collect()
 }
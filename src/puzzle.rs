@@ -0,0 +1,191 @@
+use crate::{fixtures::Example, Part};
+
+enum Token<'a> {
+    // everything between < and >
+    Tag(&'a str),
+    Text(&'a str),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+    while !rest.is_empty() {
+        match rest.find('<') {
+            Some(0) => match rest.find('>') {
+                Some(end) => {
+                    tokens.push(Token::Tag(&rest[1..end]));
+                    rest = &rest[end + 1..];
+                }
+                None => {
+                    tokens.push(Token::Text(rest));
+                    rest = "";
+                }
+            },
+            Some(start) => {
+                tokens.push(Token::Text(&rest[..start]));
+                rest = &rest[start..];
+            }
+            None => {
+                tokens.push(Token::Text(rest));
+                rest = "";
+            }
+        }
+    }
+    tokens
+}
+
+fn tag_name(tag: &str) -> &str {
+    tag.split_whitespace().next().unwrap_or_default()
+}
+
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    tag[start..].find('"').map(|end| &tag[start..start + end])
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+// the text of a page without its tags
+pub fn strip_tags(html: &str) -> String {
+    tokenize(html)
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(unescape(text)),
+            Token::Tag(_) => None,
+        })
+        .collect()
+}
+
+// each part's description, part a first (part b only shows once part a is solved)
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find("<article") {
+        let end = rest[start..]
+            .find("</article>")
+            .map_or(rest.len(), |end| start + end + "</article>".len());
+        articles.push(&rest[start..end]);
+        rest = &rest[end..];
+    }
+    articles
+}
+
+pub fn to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    for article in articles(html) {
+        let (mut in_pre, mut in_code) = (false, false);
+        let mut links = vec![];
+        for token in tokenize(article) {
+            match token {
+                // layout between tags, as the tags themselves decide the spacing
+                Token::Text(text) if !in_pre && text.trim().is_empty() && text.contains('\n') => {}
+                Token::Text(text) => markdown.push_str(&unescape(text)),
+                Token::Tag(tag) => match tag_name(tag) {
+                    "h2" => markdown.push_str("## "),
+                    "/h2" | "/p" => markdown.push_str("\n\n"),
+                    "/ul" => markdown.push('\n'),
+                    "pre" => {
+                        in_pre = true;
+                        markdown.push_str("```\n");
+                    }
+                    "/pre" => {
+                        in_pre = false;
+                        markdown.push_str("```\n\n");
+                    }
+                    "code" if !in_pre => {
+                        in_code = true;
+                        markdown.push('`');
+                    }
+                    "/code" if !in_pre => {
+                        in_code = false;
+                        markdown.push('`');
+                    }
+                    // markdown can't emphasise inside code
+                    "em" | "/em" if !in_pre && !in_code => markdown.push('*'),
+                    "li" => markdown.push_str("- "),
+                    "/li" => markdown.push('\n'),
+                    "a" => {
+                        links.push(attribute(tag, "href").unwrap_or_default());
+                        markdown.push('[');
+                    }
+                    "/a" => markdown.push_str(&format!("]({})", links.pop().unwrap_or_default())),
+                    _ => {}
+                },
+            }
+        }
+    }
+    format!("{}\n", markdown.trim_end())
+}
+
+// the answer for each part is the last <code><em> in its description, and is for
+// the last example block before it (or the previous part's example if it has none)
+pub fn extract_examples(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = vec![];
+    for (article, part) in articles(html).into_iter().zip([Part::A, Part::B]) {
+        let mut blocks = vec![];
+        let mut answer = None;
+        let (mut in_pre, mut in_code, mut in_answer) = (false, false, false);
+        let mut text = String::new();
+        for token in tokenize(article) {
+            match token {
+                Token::Text(part_text) if in_pre || in_answer => {
+                    text.push_str(&unescape(part_text))
+                }
+                Token::Text(_) => {}
+                Token::Tag(tag) => match tag_name(tag) {
+                    "pre" => {
+                        in_pre = true;
+                        text.clear();
+                    }
+                    "/pre" => {
+                        in_pre = false;
+                        blocks.push(text.clone());
+                    }
+                    "code" if !in_pre => in_code = true,
+                    "/code" if !in_pre => in_code = false,
+                    "em" if in_code => {
+                        in_answer = true;
+                        text.clear();
+                    }
+                    "/em" if in_answer => {
+                        in_answer = false;
+                        answer = Some((text.clone(), blocks.len()));
+                    }
+                    _ => {}
+                },
+            }
+        }
+
+        let Some((answer, blocks_before)) = answer else {
+            continue;
+        };
+        let input = match blocks_before.checked_sub(1) {
+            Some(index) => blocks[index].clone(),
+            None => match examples.last() {
+                Some(example) => example.input.clone(),
+                None => continue,
+            },
+        };
+        match examples.iter_mut().find(|example| example.input == input) {
+            Some(example) => example.expected[part.index()] = Some(answer),
+            None => {
+                let mut expected = [None, None];
+                expected[part.index()] = Some(answer);
+                examples.push(Example {
+                    name: format!("example {}", examples.len() + 1),
+                    input,
+                    params: vec![],
+                    expected,
+                });
+            }
+        }
+    }
+    examples
+}
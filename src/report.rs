@@ -0,0 +1,177 @@
+use anyhow::Error;
+use chrono::Utc;
+use itertools::Itertools;
+use std::{
+    fmt::Write,
+    fs::{create_dir_all, write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::{
+    answers::{AnswerStore, Check},
+    json::{DayRecord, PartRecord},
+    short_duration_to_str, Output, Part,
+};
+
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2em; color: #ccc; background: #0f0f23; }
+h1, h2 { color: #ffff66; font-weight: normal; }
+table { border-collapse: collapse; }
+th, td { padding: 0.2em 0.8em; text-align: left; border-bottom: 1px solid #333; }
+td.time { text-align: right; }
+.pass { color: #00cc00; }
+.fail { color: #ff4444; }
+.unknown { color: #ffff66; }
+.error { color: #ff4444; font-size: 0.9em; }
+.chart { display: grid; grid-template-columns: max-content 1fr max-content; gap: 0.2em 0.8em; max-width: 60em; }
+.bar { background: #009900; height: 1em; align-self: center; min-width: 1px; }
+a { color: #009900; }
+";
+
+// where --trace writes when there's a report, so the report can link to it
+pub fn trace_path(report: &Path) -> PathBuf {
+    report.with_extension("trace.json")
+}
+
+// a single html file with the answers, whether they're right, and a chart of
+// how long each day took
+pub fn write_report(
+    path: &Path,
+    outputs: &[(u32, u32, Result<Output, Error>)],
+    duration: Duration,
+    trace: Option<&Path>,
+) -> Result<(), Error> {
+    let records = outputs
+        .iter()
+        .map(|(year, day, output)| {
+            Ok((
+                DayRecord::new(*day, *year, output),
+                AnswerStore::load(*day, *year)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let mut html = String::new();
+    writeln!(
+        html,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Advent of Code run</title>\n<style>{STYLE}</style>\n</head>\n<body>"
+    )?;
+    writeln!(html, "<h1>Advent of Code run</h1>")?;
+    let failed = records
+        .iter()
+        .filter(|(record, _)| record.error.is_some())
+        .count();
+    writeln!(
+        html,
+        "<p><code>{}</code> at {}, {} days in {}, <span class=\"{}\">{} failed</span></p>",
+        escape(&std::env::args().skip(1).join(" ")),
+        Utc::now().format("%Y-%m-%d %H:%M UTC"),
+        records.len(),
+        short_duration_to_str(duration),
+        if failed > 0 { "fail" } else { "pass" },
+        failed
+    )?;
+    if let Some(trace) = trace {
+        // the trace is only written once the run exits, so it's linked rather than embedded
+        let name = trace.file_name().unwrap_or_default().to_string_lossy();
+        writeln!(
+            html,
+            "<p>Trace: <a href=\"{0}\">{0}</a> (open it in <a href=\"https://ui.perfetto.dev\">Perfetto</a> or chrome://tracing)</p>",
+            escape(&name)
+        )?;
+    }
+
+    writeln!(html, "<h2>Answers</h2>\n<table>")?;
+    writeln!(
+        html,
+        "<tr><th>Year</th><th>Day</th><th>Test</th><th>Part a</th><th>Part b</th><th>Time</th></tr>"
+    )?;
+    for (record, store) in records.iter() {
+        let test_class = match record.test.as_str() {
+            "passed" => "pass",
+            "failed" => "fail",
+            _ => "unknown",
+        };
+        writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td class=\"{test_class}\">{}</td>{}{}<td class=\"time\">{}</td></tr>",
+            record.year,
+            record.day,
+            record.test,
+            part_cell(Part::A, record.part_a.as_ref(), store),
+            part_cell(Part::B, record.part_b.as_ref(), store),
+            record.solve_duration_us.map_or("-".into(), micros_to_str),
+        )?;
+        if let Some(err) = &record.error {
+            writeln!(
+                html,
+                "<tr><td></td><td></td><td colspan=\"4\" class=\"error\">{}</td></tr>",
+                escape(err)
+            )?;
+        }
+    }
+    writeln!(html, "</table>")?;
+
+    // bars are scaled to the slowest day
+    writeln!(html, "<h2>Time to solve</h2>\n<div class=\"chart\">")?;
+    let slowest = records
+        .iter()
+        .filter_map(|(record, _)| record.solve_duration_us)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    for (record, _) in records.iter() {
+        let Some(micros) = record.solve_duration_us else {
+            continue;
+        };
+        writeln!(
+            html,
+            "<div>{} day {}</div><div class=\"bar\" style=\"width: {:.2}%\"></div><div>{}</div>",
+            record.year,
+            record.day,
+            100.0 * micros as f64 / slowest as f64,
+            micros_to_str(micros)
+        )?;
+    }
+    writeln!(html, "</div>\n</body>\n</html>")?;
+
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+    write(path, html)?;
+    Ok(())
+}
+
+fn part_cell(part: Part, record: Option<&PartRecord>, store: &AnswerStore) -> String {
+    let Some(record) = record else {
+        return "<td>-</td>".into();
+    };
+    let check = store.check(part, Some(&record.answer));
+    let class = match check {
+        Check::Pass => "pass",
+        Check::Fail | Check::Changed => "fail",
+        Check::Unknown => "unknown",
+    };
+    let expected = match (check, store.get(part)) {
+        (Check::Fail | Check::Changed, Some((_, expected))) => {
+            format!(" (expected {})", escape(expected))
+        }
+        _ => String::default(),
+    };
+    format!(
+        "<td><code>{}</code> <span class=\"{class}\">{check}</span>{expected}</td>",
+        escape(&record.answer)
+    )
+}
+
+fn micros_to_str(micros: u128) -> String {
+    short_duration_to_str(Duration::from_micros(micros as u64))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,111 @@
+use anyhow::{bail, Error};
+use std::{
+    fs::{create_dir_all, read_to_string, write},
+    path::PathBuf,
+};
+
+const TEMPLATE: &str = include_str!("../template/day.rs");
+const FUZZ_TEMPLATE: &str = include_str!("../template/fuzz_target.rs");
+
+// writes src/y{year}/day{day}.rs from the template and registers it, creating the
+// year's module if it's the first day, returning the new file
+pub fn new_day(day: u32, year: u32) -> Result<PathBuf, Error> {
+    if !(1..=25).contains(&day) {
+        bail!("there's no day {}", day);
+    }
+    let dir: PathBuf = format!("src/y{year}").into();
+    let path = dir.join(format!("day{day}.rs"));
+    if path.exists() {
+        bail!("{} already exists", path.display());
+    }
+
+    let mod_path = dir.join("mod.rs");
+    let registrations = match read_to_string(&mod_path) {
+        Ok(data) => data,
+        Err(_) => {
+            add_year(year)?;
+            String::new()
+        }
+    };
+    let registration = format!("add_day!({year}, {day}, day{day});");
+    if registrations.contains(&registration) {
+        bail!(
+            "day {} is already registered in {}",
+            day,
+            mod_path.display()
+        );
+    }
+
+    // keep registrations in day order
+    let mut lines = registrations.lines().map(String::from).collect::<Vec<_>>();
+    let position = lines
+        .iter()
+        .position(|line| registered_day(line).is_some_and(|other| other > day))
+        .unwrap_or(lines.len());
+    lines.insert(position, registration);
+
+    create_dir_all(&dir)?;
+    write(
+        &path,
+        TEMPLATE
+            .replace("DAY", &day.to_string())
+            .replace("YEAR", &year.to_string()),
+    )?;
+    write(&mod_path, lines.join("\n") + "\n")?;
+    add_fuzz_target(day, year)?;
+    Ok(path)
+}
+
+fn registered_day(line: &str) -> Option<u32> {
+    line.strip_prefix("add_day!(")?
+        .split(',')
+        .nth(1)?
+        .trim()
+        .parse()
+        .ok()
+}
+
+// writes the day's fuzz target and adds it to the fuzz crate, if there is one
+fn add_fuzz_target(day: u32, year: u32) -> Result<(), Error> {
+    let manifest_path = "fuzz/Cargo.toml";
+    let Ok(manifest) = read_to_string(manifest_path) else {
+        return Ok(());
+    };
+    let name = format!("y{year}_day{day}");
+    let path = format!("fuzz_targets/y{year}/day{day}.rs");
+    create_dir_all(format!("fuzz/fuzz_targets/y{year}"))?;
+    write(
+        format!("fuzz/{path}"),
+        FUZZ_TEMPLATE
+            .replace("DAY", &day.to_string())
+            .replace("YEAR", &year.to_string()),
+    )?;
+    if !manifest.contains(&format!("name = \"{name}\"")) {
+        write(
+            manifest_path,
+            format!(
+                "{manifest}\n[[bin]]\nname = \"{name}\"\npath = \"{path}\"\ntest = false\ndoc = false\nbench = false\n"
+            ),
+        )?;
+    }
+    Ok(())
+}
+
+// declares the year's module in lib.rs, after the other years
+fn add_year(year: u32) -> Result<(), Error> {
+    let lib_path = "src/lib.rs";
+    let lib = read_to_string(lib_path)?;
+    let declaration = format!("pub mod y{year};\n");
+    let position = match lib.rfind("\npub mod y") {
+        Some(last) => last + 1 + lib[last + 1..].find('\n').unwrap() + 1,
+        None => match lib.find("collect!(Solution);") {
+            Some(collect) => collect,
+            None => bail!("cannot find where to declare y{} in {}", year, lib_path),
+        },
+    };
+    write(
+        lib_path,
+        format!("{}{}{}", &lib[..position], declaration, &lib[position..]),
+    )?;
+    Ok(())
+}
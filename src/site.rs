@@ -0,0 +1,108 @@
+use anyhow::{anyhow, bail, Error};
+use reqwest::{Client, Method, RequestBuilder};
+use std::{
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+use crate::Part;
+
+// requests to the advent of code site (or a local stand-in for it),
+// using the session file and user agent
+#[derive(Clone, Debug)]
+pub struct Site {
+    base_url: String,
+    // refuse every request, so nothing touches the network
+    offline: bool,
+    session_file: PathBuf,
+    user_agent: Option<String>,
+}
+
+impl Site {
+    pub fn new(
+        base_url: &str,
+        offline: bool,
+        session_file: &Path,
+        user_agent: Option<&str>,
+    ) -> Self {
+        Site {
+            base_url: base_url.trim_end_matches('/').into(),
+            offline,
+            session_file: session_file.into(),
+            user_agent: user_agent.map(Into::into),
+        }
+    }
+
+    fn request(&self, method: Method, path: &str) -> Result<RequestBuilder, Error> {
+        if self.offline {
+            bail!("offline, so not fetching {}", path);
+        }
+        let session = read_to_string(&self.session_file).map_err(|_| {
+            anyhow!(
+                "cannot find {} (needed for talking to the site)",
+                self.session_file.display()
+            )
+        })?;
+        // user-agent.txt is where it used to have to be
+        let user_agent = match &self.user_agent {
+            Some(user_agent) => user_agent.clone(),
+            None => read_to_string("user-agent.txt").map_err(|_| {
+                anyhow!("no user agent, set user_agent in aoc.toml, AOC_USER_AGENT or --user-agent")
+            })?,
+        };
+        Ok(Client::new()
+            .request(method, format!("{}{}", self.base_url, path))
+            .header("Cookie", format!("session={}", session.trim()))
+            .header("User-Agent", user_agent.trim()))
+    }
+
+    pub async fn get_input(&self, day: u32, year: u32) -> Result<String, Error> {
+        let response = self
+            .request(Method::GET, &format!("/{year}/day/{day}/input"))?
+            .send()
+            .await?;
+        Ok(response.error_for_status()?.text().await?)
+    }
+
+    pub async fn get_puzzle(&self, day: u32, year: u32) -> Result<String, Error> {
+        let response = self
+            .request(Method::GET, &format!("/{year}/day/{day}"))?
+            .send()
+            .await?;
+        Ok(response.error_for_status()?.text().await?)
+    }
+
+    pub async fn get_leaderboard(&self, year: u32, id: u64) -> Result<String, Error> {
+        let response = self
+            .request(
+                Method::GET,
+                &format!("/{year}/leaderboard/private/view/{id}.json"),
+            )?
+            .send()
+            .await?;
+        Ok(response.error_for_status()?.text().await?)
+    }
+
+    pub async fn get_personal_stats(&self, year: u32) -> Result<String, Error> {
+        let response = self
+            .request(Method::GET, &format!("/{year}/leaderboard/self"))?
+            .send()
+            .await?;
+        Ok(response.error_for_status()?.text().await?)
+    }
+
+    pub async fn post_answer(
+        &self,
+        day: u32,
+        year: u32,
+        part: Part,
+        answer: &str,
+    ) -> Result<String, Error> {
+        let response = self
+            .request(Method::POST, &format!("/{year}/day/{day}/answer"))?
+            .form(&[("level", part.level()), ("answer", answer)])
+            .send()
+            .await?;
+        Ok(response.error_for_status()?.text().await?)
+    }
+}
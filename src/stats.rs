@@ -0,0 +1,179 @@
+use anyhow::{bail, Error};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::{
+    answers::{AnswerStore, Check, Source},
+    bench::{self, DayStats},
+    cache::Cache,
+    leaderboard, puzzle,
+    site::Site,
+    verdicts::{Verdict, VerdictLog},
+    Output, Part,
+};
+
+// one part of a day as the site's personal stats page shows it
+#[derive(Debug, Clone)]
+pub struct SitePart {
+    // from when the puzzle unlocked, ">24h" once it's more than a day
+    pub time: String,
+    pub rank: u32,
+    pub score: u32,
+}
+
+// day -> parts a and b, for days with at least one star
+pub type SiteStats = BTreeMap<u32, [Option<SitePart>; 2]>;
+
+// cached like private leaderboards, as it changes about as often
+pub async fn load(site: &Site, cache: &Cache, year: u32) -> Result<SiteStats, Error> {
+    let html = cache
+        .fetch_page(
+            &cache.personal_stats_path(year),
+            leaderboard::REFRESH,
+            || async {
+                let html = site.get_personal_stats(year).await?;
+                parse(&html)?;
+                Ok(html)
+            },
+        )
+        .await?;
+    parse(&html)
+}
+
+// rows of "day time rank score time rank score" in the page's <pre>, with "-"
+// for a part not solved yet
+fn parse(html: &str) -> Result<SiteStats, Error> {
+    if html.contains("/auth/login") {
+        bail!("not a personal stats page (has the session expired?)");
+    }
+    let mut stats = SiteStats::new();
+    for line in puzzle::strip_tags(html).lines() {
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        let Some(Ok(day)) = fields.first().map(|field| field.parse::<u32>()) else {
+            continue;
+        };
+        if fields.len() != 7 {
+            bail!("bad personal stats line '{}'", line.trim());
+        }
+        let part = |fields: &[&str]| -> Result<Option<SitePart>, Error> {
+            match fields {
+                ["-", "-", "-"] => Ok(None),
+                [time, rank, score] => Ok(Some(SitePart {
+                    time: time.to_string(),
+                    rank: rank.parse()?,
+                    score: score.parse()?,
+                })),
+                _ => unreachable!(),
+            }
+        };
+        stats.insert(day, [part(&fields[1..4])?, part(&fields[4..7])?]);
+    }
+    Ok(stats)
+}
+
+// one day of the stats command, as shown and as emitted by --format json/jsonl
+#[derive(Debug, Serialize)]
+pub struct DayRow {
+    pub day: u32,
+    pub solution: bool,
+    pub part_a: PartRow,
+    pub part_b: PartRow,
+    // why the solution couldn't give answers
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PartRow {
+    pub star: bool,
+    // from when the puzzle unlocked
+    pub time_to_solve: Option<String>,
+    pub rank: Option<u32>,
+    pub score: Option<u32>,
+    // the bench baseline's median if there is one, otherwise this run's time
+    pub runtime_ns: Option<f64>,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    // None if the solution wasn't run
+    pub check: Option<String>,
+}
+
+impl DayRow {
+    // the site's stats are preferred, then the verdicts submit has seen
+    pub fn new(
+        day: u32,
+        year: u32,
+        solution: bool,
+        site: Option<&[Option<SitePart>; 2]>,
+        local: (&VerdictLog, &AnswerStore, Option<&DayStats>),
+        run: Option<&Result<Output, Error>>,
+    ) -> Self {
+        let (verdicts, store, bench) = local;
+        let output = run.and_then(|run| run.as_ref().ok());
+        let part = |part: Part| {
+            let site = site.and_then(|site| site[part.index()].as_ref());
+            let accepted = verdicts.submissions.iter().find(|submission| {
+                submission.part == part && submission.verdict == Verdict::Correct
+            });
+            let answer = output.and_then(|output| {
+                output
+                    .parts
+                    .iter()
+                    .find(|(run_part, _, _)| *run_part == part)
+            });
+            let check = output.map(|_| {
+                store.check(
+                    part,
+                    answer.map(|(_, answer, _)| answer.to_string()).as_deref(),
+                )
+            });
+            PartRow {
+                star: site.is_some()
+                    || accepted.is_some()
+                    || matches!(store.get(part), Some((Source::Accepted, _))),
+                time_to_solve: site.map(|site| site.time.clone()).or_else(|| {
+                    accepted.map(|submission| {
+                        leaderboard::clock(submission.time - leaderboard::unlock_ts(day, year))
+                    })
+                }),
+                rank: site.map(|site| site.rank),
+                score: site.map(|site| site.score),
+                runtime_ns: bench
+                    .and_then(|bench| bench.get(&part.to_string()))
+                    .map(|stats| stats.median)
+                    .or_else(|| answer.map(|(_, _, duration)| duration.as_nanos() as f64)),
+                answer: answer.map(|(_, answer, _)| answer.to_string()),
+                expected: match check {
+                    Some(Check::Fail | Check::Changed) => {
+                        store.get(part).map(|(_, expected)| expected.clone())
+                    }
+                    _ => None,
+                },
+                check: check.map(|check| check.to_string()),
+            }
+        };
+        DayRow {
+            day,
+            solution,
+            part_a: part(Part::A),
+            part_b: part(Part::B),
+            error: run
+                .and_then(|run| run.as_ref().err())
+                .map(|err| format!("{:#}", err)),
+        }
+    }
+
+    // no solution, or one that no longer gives the known answers
+    pub fn needs_attention(&self) -> bool {
+        !self.solution
+            || self.error.is_some()
+            || [&self.part_a, &self.part_b]
+                .iter()
+                .any(|part| matches!(part.check.as_deref(), Some("fail" | "changed")))
+    }
+}
+
+impl PartRow {
+    pub fn runtime(&self) -> String {
+        self.runtime_ns.map_or("-".into(), bench::nanos_to_str)
+    }
+}
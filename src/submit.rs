@@ -0,0 +1,41 @@
+use advent_2023::{
+    answers::{AnswerStore, Source},
+    cache::Cache,
+    verdicts::{parse_response, Submission, Verdict, VerdictLog},
+    Part,
+};
+use anyhow::Error;
+use chrono::Utc;
+
+use crate::site::Site;
+
+pub async fn submit(
+    site: &Site,
+    cache: &Cache,
+    day: u32,
+    year: u32,
+    part: Part,
+    answer: &str,
+) -> Result<Submission, Error> {
+    let mut log = VerdictLog::load(cache, day, year)?;
+    log.check(part, answer, Utc::now().timestamp())?;
+
+    let html = site.post_answer(day, year, part, answer).await?;
+    let time = Utc::now().timestamp();
+    let (verdict, cooldown) = parse_response(&html)?;
+    let submission = Submission {
+        time,
+        part,
+        verdict,
+        cooldown,
+        answer: answer.into(),
+    };
+    log.record(submission.clone())?;
+
+    if verdict == Verdict::Correct {
+        let mut store = AnswerStore::load(day, year)?;
+        store.set(part, Source::Accepted, answer);
+        store.save()?;
+    }
+    Ok(submission)
+}
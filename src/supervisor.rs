@@ -0,0 +1,68 @@
+use anyhow::{anyhow, Error};
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    panic::{catch_unwind, AssertUnwindSafe},
+    time::Duration,
+};
+
+thread_local! {
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+    // set on the threads supervise runs solvers on
+    static SUPERVISED: Cell<bool> = const { Cell::new(false) };
+}
+
+// keep a solver's panics out of the report, remembering where they happened for the
+// error instead. any other panic goes to the hook that was there before
+pub fn install_panic_hook() {
+    let default = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if !SUPERVISED.get() {
+            return default(info);
+        }
+        PANIC_LOCATION.with(|location| {
+            *location.borrow_mut() = info.location().map(|location| location.to_string())
+        });
+        tracing::debug!("{}", info);
+    }));
+}
+
+// run a solver on its own thread, turning a panic or running out of time into an error.
+// uses a plain thread rather than spawn_blocking as a solver that times out is left
+// running, and tokio would wait for it when shutting down
+pub async fn supervise<T, F>(timeout: Option<Duration>, f: F) -> Result<T, Error>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let (sender, receiver) = tokio::sync::oneshot::channel();
+    std::thread::spawn(move || {
+        SUPERVISED.set(true);
+        let result = catch_unwind(AssertUnwindSafe(f)).map_err(|panic| {
+            let location = PANIC_LOCATION.with(|location| location.borrow_mut().take());
+            match location {
+                Some(location) => anyhow!("panicked at {}: {}", location, message(&panic)),
+                None => anyhow!("panicked: {}", message(&panic)),
+            }
+        });
+        let _ = sender.send(result);
+    });
+
+    let result = match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, receiver)
+            .await
+            .map_err(|_| anyhow!("timed out after {}s", timeout.as_secs_f64()))?,
+        None => receiver.await,
+    };
+    result?
+}
+
+fn message(panic: &Box<dyn Any + Send>) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".into()
+    }
+}
@@ -1,10 +1,6 @@
+// 1 for an empty slice, so a fold can start from it
 pub fn lcm(nums: &[usize]) -> usize {
-    if nums.len() == 1 {
-        nums[0]
-    } else {
-        let inner = lcm(&nums[1..]);
-        nums[0] * inner / gcd(nums[0], inner)
-    }
+    nums.iter().fold(1, |lcm, &num| lcm / gcd(lcm, num) * num)
 }
 
 pub fn gcd(a: usize, b: usize) -> usize {
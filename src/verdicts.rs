@@ -0,0 +1,228 @@
+use anyhow::{anyhow, bail, Error};
+use std::{
+    fmt,
+    fs::{create_dir_all, read_to_string, OpenOptions},
+    io::Write,
+    path::PathBuf,
+    str::FromStr,
+};
+
+use crate::{cache::Cache, Part};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    Wait,
+    WrongLevel,
+}
+
+impl Verdict {
+    pub fn is_rejection(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait => "wait",
+            Verdict::WrongLevel => "wrong-level",
+        })
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "correct" => Verdict::Correct,
+            "too-high" => Verdict::TooHigh,
+            "too-low" => Verdict::TooLow,
+            "wrong" => Verdict::Wrong,
+            "wait" => Verdict::Wait,
+            "wrong-level" => Verdict::WrongLevel,
+            _ => bail!("unknown verdict '{}'", s),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Submission {
+    pub time: i64,
+    pub part: Part,
+    pub verdict: Verdict,
+    pub cooldown: i64,
+    pub answer: String,
+}
+
+impl fmt::Display for Submission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}",
+            self.time, self.part, self.verdict, self.cooldown, self.answer
+        )
+    }
+}
+
+impl FromStr for Submission {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.splitn(5, '\t');
+        let mut next = || {
+            split
+                .next()
+                .ok_or_else(|| anyhow!("bad verdict line '{}'", s))
+        };
+        Ok(Submission {
+            time: next()?.parse()?,
+            part: next()?.parse()?,
+            verdict: next()?.parse()?,
+            cooldown: next()?.parse()?,
+            answer: next()?.into(),
+        })
+    }
+}
+
+// every verdict the site has given for one day, one per line in {cache}/{year}/day{day}.verdicts
+pub struct VerdictLog {
+    path: PathBuf,
+    pub submissions: Vec<Submission>,
+}
+
+impl VerdictLog {
+    pub fn load(cache: &Cache, day: u32, year: u32) -> Result<Self, Error> {
+        let path = cache.verdicts_path(day, year);
+        let submissions = match read_to_string(&path) {
+            Ok(data) => data
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(|line| line.parse())
+                .collect::<Result<_, _>>()?,
+            Err(_) => vec![],
+        };
+        Ok(VerdictLog { path, submissions })
+    }
+
+    pub fn record(&mut self, submission: Submission) -> Result<(), Error> {
+        create_dir_all(self.path.parent().unwrap())?;
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", submission)?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+
+    // errors if this answer can be ruled out locally, or the cooldown is still running
+    pub fn check(&self, part: Part, answer: &str, now: i64) -> Result<(), Error> {
+        let as_num = |answer: &str| answer.parse::<i128>().ok();
+        for submission in self.submissions.iter().filter(|s| s.part == part) {
+            if submission.verdict == Verdict::Correct {
+                bail!("part {} already solved with '{}'", part, submission.answer);
+            }
+            if submission.verdict.is_rejection() && submission.answer == answer {
+                bail!("'{}' was already rejected ({})", answer, submission.verdict);
+            }
+            if let (Some(num), Some(bound)) = (as_num(answer), as_num(&submission.answer)) {
+                if submission.verdict == Verdict::TooHigh && num > bound {
+                    bail!("'{}' is above '{}' which was too high", answer, bound);
+                }
+                if submission.verdict == Verdict::TooLow && num < bound {
+                    bail!("'{}' is below '{}' which was too low", answer, bound);
+                }
+            }
+        }
+        if let Some(until) = self.submissions.iter().map(|s| s.time + s.cooldown).max() {
+            if now < until {
+                bail!("still cooling down, {}s left to wait", until - now);
+            }
+        }
+        Ok(())
+    }
+}
+
+// get verdict and cooldown (in seconds) from the html the site returns
+pub fn parse_response(html: &str) -> Result<(Verdict, i64), Error> {
+    let text = article_text(html);
+    let verdict = if text.contains("That's the right answer") {
+        Verdict::Correct
+    } else if text.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if text.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if text.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else if text.contains("You gave an answer too recently") {
+        Verdict::Wait
+    } else if text.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        bail!("unrecognised response '{}'", text.trim());
+    };
+    Ok((verdict, parse_cooldown(&text)))
+}
+
+fn article_text(html: &str) -> String {
+    let article = html
+        .find("<article")
+        .and_then(|start| {
+            html[start..]
+                .find("</article>")
+                .map(|end| &html[start..start + end])
+        })
+        .unwrap_or(html);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for ch in article.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    text
+}
+
+// handles "You have 1m 20s left to wait" and "please wait one minute before trying again"
+pub fn parse_cooldown(text: &str) -> i64 {
+    if let Some(start) = text.find("You have ") {
+        let rest = &text[start + "You have ".len()..];
+        rest[..rest.find(" left to wait").unwrap_or(0)]
+            .split_ascii_whitespace()
+            .filter_map(|part| {
+                [("s", 1), ("m", 60), ("h", 60 * 60)]
+                    .into_iter()
+                    .find_map(|(unit, mult)| {
+                        part.strip_suffix(unit)
+                            .and_then(|num| num.parse::<i64>().ok())
+                            .map(|num| num * mult)
+                    })
+            })
+            .sum()
+    } else if let Some(start) = text.find("wait ") {
+        let mut words = text[start + "wait ".len()..].split_ascii_whitespace();
+        let num = match words.next() {
+            Some("one") => Some(1),
+            Some(word) => word.parse::<i64>().ok(),
+            None => None,
+        };
+        match (num, words.next()) {
+            (Some(num), Some(unit)) if unit.starts_with("second") => num,
+            (Some(num), Some(unit)) if unit.starts_with("minute") => num * 60,
+            (Some(num), Some(unit)) if unit.starts_with("hour") => num * 60 * 60,
+            _ => 0,
+        }
+    } else {
+        0
+    }
+}
@@ -0,0 +1,150 @@
+use anyhow::Error;
+use itertools::Itertools;
+use std::{
+    fs::metadata,
+    path::PathBuf,
+    time::{Duration, SystemTime},
+};
+use tokio::process::Command;
+
+use crate::{day_prefix, json::DayRecord, Args};
+
+const POLL: Duration = Duration::from_millis(500);
+
+// rebuilds and reruns a day through cargo whenever one of the paths changes, until killed.
+// the run does the example first and only goes on to the real input if it passes
+pub async fn watch(day: u32, year: u32, args: &Args, paths: Vec<PathBuf>) -> Result<(), Error> {
+    let run_args = child_args(day, year, args);
+    let mut last_seen = None;
+    loop {
+        let seen = modified(&paths);
+        if last_seen.as_ref() != Some(&seen) {
+            if last_seen.is_some() {
+                // give the editor a moment to finish saving
+                tokio::time::sleep(POLL).await;
+            }
+            last_seen = Some(modified(&paths));
+            run_once(day, &run_args).await;
+            tracing::info!(
+                "\x1b[93mwatching {}\x1b[0m",
+                paths.iter().map(|path| path.display()).join(", ")
+            );
+        }
+        tokio::time::sleep(POLL).await;
+    }
+}
+
+fn modified(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| metadata(path).and_then(|meta| meta.modified()).ok())
+        .collect()
+}
+
+// the same day and options, as json so the result can be picked apart
+fn child_args(day: u32, year: u32, args: &Args) -> Vec<String> {
+    let mut run_args = vec![
+        "--day".into(),
+        day.to_string(),
+        "--year".into(),
+        year.to_string(),
+        "--format".into(),
+        "jsonl".into(),
+        "--cache-dir".into(),
+        args.cache_dir.display().to_string(),
+        "--session-file".into(),
+        args.session_file.display().to_string(),
+        "--base-url".into(),
+        args.base_url.clone(),
+    ];
+    if let Some(user_agent) = &args.user_agent {
+        run_args.extend(["--user-agent".into(), user_agent.clone()]);
+    }
+    if let Some(part) = args.part {
+        run_args.extend(["--part".into(), part.to_string()]);
+    }
+    if let Some(input) = &args.input {
+        run_args.extend(["--input".into(), input.display().to_string()]);
+    }
+    if let Some(timeout) = args.timeout {
+        run_args.extend(["--timeout".into(), timeout.to_string()]);
+    }
+    if args.offline {
+        run_args.push("--offline".into());
+    }
+    if args.no_test {
+        run_args.push("--no-test".into());
+    }
+    if args.reference {
+        run_args.push("--reference".into());
+    }
+    run_args
+}
+
+async fn run_once(day: u32, run_args: &[String]) {
+    let prefix = day_prefix(day);
+    // build errors and the run's own logging go straight through on stderr
+    let output = match Command::new("cargo")
+        .args(["run", "--quiet", "--"])
+        .args(run_args)
+        .output()
+        .await
+    {
+        Ok(output) => output,
+        Err(err) => {
+            tracing::error!("{}cannot run cargo: {}", prefix, err);
+            return;
+        }
+    };
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let records = stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<DayRecord>(line).ok())
+        .collect_vec();
+    if records.is_empty() {
+        tracing::error!("{}build or run failed", prefix);
+    }
+    for record in records {
+        write_record(&prefix, &record);
+    }
+}
+
+fn write_record(prefix: &str, record: &DayRecord) {
+    if record.test == "failed" {
+        for example in record.examples.iter().filter(|example| !example.passed) {
+            tracing::error!(
+                "{}failed example '{}' part {}",
+                prefix,
+                example.name,
+                example.part
+            );
+            for line in diff(&example.expected, &example.got) {
+                tracing::info!("{}{}", prefix, line);
+            }
+        }
+    } else if let Some(err) = &record.error {
+        tracing::error!("{}{}", prefix, err);
+    } else {
+        let answers = [&record.part_a, &record.part_b]
+            .into_iter()
+            .flatten()
+            .map(|part| format!("{}\x1b[93m ({}μs)\x1b[0m", part.answer, part.duration_us))
+            .join(" / ");
+        tracing::info!("{}\x1b[32mtest {}\x1b[0m {}", prefix, record.test, answers);
+    }
+}
+
+// line by line, for answers that are drawings
+fn diff(expected: &str, got: &str) -> Vec<String> {
+    let expected = expected.lines().collect_vec();
+    let got = got.lines().collect_vec();
+    (0..expected.len().max(got.len()))
+        .flat_map(|index| match (expected.get(index), got.get(index)) {
+            (Some(expected), Some(got)) if expected == got => vec![format!("  {}", got)],
+            (expected, got) => vec![
+                format!("\x1b[32m- {}\x1b[0m", expected.unwrap_or(&"")),
+                format!("\x1b[31m+ {}\x1b[0m", got.unwrap_or(&"")),
+            ],
+        })
+        .collect()
+}
@@ -0,0 +1,100 @@
+use anyhow::Error;
+use fastrand::Rng;
+use itertools::Itertools;
+
+use crate::{fixtures::Example, input::{non_empty_lines, Line, ParseError}};
+
+#[tracing::instrument(fields(day=1))]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    Ok(vec![
+        Example::new(
+            "digits",
+            "1abc2
+    pqr3stu8vwx
+    a1b2c3d4e5f
+    treb7uchet",
+        )
+        .part_a("142"),
+        Example::new(
+            "words",
+            "two1nine
+    eightwothree
+    abcone2threexyz
+    xtwone3four
+    4nineeightseven2
+    zoneight234
+    7pqrstsixteen",
+        )
+        .part_b("281"),
+    ])
+}
+
+// the first and last digits make a two digit number
+fn calibration(line: Line, nums: &[u32]) -> Result<u32, ParseError> {
+    match (nums.first(), nums.last()) {
+        (Some(first), Some(last)) => Ok(first * 10 + last),
+        _ => Err(line.error(line.text, "no digits")),
+    }
+}
+
+#[tracing::instrument(skip(input), fields(day=1))]
+pub fn part_a(input: &str) -> Result<u32, Error> {
+    Ok(non_empty_lines(input)
+        .map(|line| {
+            let nums = line
+                .text
+                .chars()
+                .filter_map(|ch| ch.to_digit(10))
+                .collect_vec();
+            calibration(line, &nums)
+        })
+        .sum::<Result<u32, _>>()?)
+}
+
+#[tracing::instrument(skip(input), fields(day=1))]
+pub fn part_b(input: &str) -> Result<u32, Error> {
+    Ok(non_empty_lines(input)
+        .map(|line| {
+            tracing::debug!("line {}", line.text);
+            let mut nums: Vec<u32> = vec![];
+            let mut rest = line.text;
+            while let Some(ch) = rest.chars().next() {
+                let digit_strs = [
+                    "", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+                ];
+                for digit in 1..10 {
+                    if rest.starts_with(char::from_digit(digit, 10).unwrap())
+                        || rest.starts_with(digit_strs[digit as usize])
+                    {
+                        nums.push(digit);
+                    }
+                }
+                rest = &rest[ch.len_utf8()..];
+            }
+            tracing::debug!("nums {:?}", nums);
+            calibration(line, &nums)
+        })
+        .sum::<Result<u32, _>>()?)
+}
+
+// lines of letters with digits and spelled out digits mixed in, always with a digit
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const WORDS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    (0..size)
+        .map(|_| {
+            let mut line = String::new();
+            let digit_at = rng.usize(0..4);
+            for index in 0..4 {
+                (0..rng.usize(0..4)).for_each(|_| line.push(rng.lowercase()));
+                if index == digit_at {
+                    line.push(rng.char('1'..='9'));
+                } else if rng.bool() {
+                    line.push_str(WORDS[rng.usize(0..WORDS.len())]);
+                }
+            }
+            line + "\n"
+        })
+        .collect()
+}
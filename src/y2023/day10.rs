@@ -1,7 +1,11 @@
+use anyhow::{anyhow, bail, Error};
+use fastrand::Rng;
 use glam::{ivec2, IVec2};
 use itertools::Itertools;
 use rustc_hash::FxHashSet;
 
+use crate::{fixtures::Example, gen, input::{grid, ParseError}};
+
 #[derive(Debug)]
 struct Route {
     pos: IVec2,
@@ -10,18 +14,20 @@ struct Route {
 }
 
 impl Route {
-    fn grow(&mut self, map: &Map) {
-        *self = Route {
-            pos: self.pos
-                + map
-                    .can_travel_from(&self.pos)
-                    .unwrap()
-                    .into_iter()
+    fn grow(&mut self, map: &Map) -> Result<(), Error> {
+        let dir = map
+            .can_travel_from(&self.pos)
+            .and_then(|dirs| {
+                dirs.into_iter()
                     .find(|&dir| self.from != self.pos + dir)
-                    .unwrap(),
+            })
+            .ok_or_else(|| anyhow!("pipe ends at {}", self.pos))?;
+        *self = Route {
+            pos: self.pos + dir,
             from: self.pos,
             len: self.len + 1,
         };
+        Ok(())
     }
 }
 
@@ -31,15 +37,14 @@ struct Map {
 }
 
 const DIRS: [IVec2; 4] = [ivec2(1, 0), ivec2(0, 1), ivec2(-1, 0), ivec2(0, -1)];
-                    
-impl From<&str> for Map {
-    fn from(value: &str) -> Self {
-        Map {
-            tiles: value
-                .lines()
-                .map(|line| line.trim_start().chars().collect_vec())
-                .collect_vec(),
-        }
+
+impl TryFrom<&str> for Map {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(Map {
+            tiles: grid(value, |ch| "|-LJ7F.S".contains(ch).then_some(ch))?,
+        })
     }
 }
 
@@ -51,26 +56,25 @@ impl Map {
             .copied()
     }
 
-    fn part_a(&self) -> usize {
-        let mut routes = self.find_starts();
+    fn part_a(&self) -> Result<usize, Error> {
+        let mut routes = self.find_starts()?;
         let mut update_index = 0;
         loop {
-            let route = routes.get_mut(update_index).unwrap();
-            route.grow(self);
+            routes[update_index].grow(self)?;
             if routes[update_index].pos == routes[1 - update_index].pos {
-                return routes[update_index].len;
+                return Ok(routes[update_index].len);
             }
             update_index = 1 - update_index;
         }
     }
 
-    fn part_b(&self) -> usize {
-        self.find_starts()
+    fn part_b(&self) -> Result<usize, Error> {
+        self.find_starts()?
             .iter_mut()
-            .find_map(|route| {
+            .map(|route| {
                 let mut edges = vec![route.pos];
                 while self.get(&route.pos) != Some('S') {
-                    route.grow(self);
+                    route.grow(self)?;
                     edges.push(route.pos);
                 }
                 edges.push(edges[0]);
@@ -109,28 +113,25 @@ impl Map {
                             &(pos_3[1] + (pos_2[1] - pos_2[0]).perp()),
                             &mut num,
                         ) {
-                            return None;
+                            return Ok(None);
                         }
                     }
                 }
 
-                Some(num)
+                Ok(Some(num))
             })
-            .unwrap()
+            .find_map(Result::transpose)
+            .ok_or_else(|| anyhow!("loop has no inside"))?
     }
 
-    fn find_starts(&self) -> [Route; 2] {
-        let pos = (|| {
-            for y in 0..self.tiles.len() as i32 {
-                for x in 0..self.tiles[0].len() as i32 {
-                    let pos = ivec2(x, y);
-                    if self.get(&pos) == Some('S') {
-                        return pos;
-                    }
-                }
-            }
-            panic!();
-        })();
+    fn find_starts(&self) -> Result<[Route; 2], Error> {
+        let Some(pos) = self.tiles.iter().enumerate().find_map(|(y, row)| {
+            row.iter()
+                .position(|&ch| ch == 'S')
+                .map(|x| ivec2(x as i32, y as i32))
+        }) else {
+            bail!("no 'S' in the map");
+        };
         DIRS.iter()
             .filter_map(|&dir| {
                 if let Some(dirs) = self.can_travel_from(&(pos + dir)) {
@@ -146,7 +147,7 @@ impl Map {
             })
             .collect_vec()
             .try_into()
-            .unwrap()
+            .map_err(|_| anyhow!("expected 2 pipes connecting to 'S'"))
     }
 
     fn can_travel_from(&self, pos: &IVec2) -> Option<[IVec2; 2]> {
@@ -165,24 +166,30 @@ impl Map {
 }
 
 #[tracing::instrument(skip(input), fields(day = 10))]
-pub fn solve(input: &str) -> String {
-    let map: Map = input.into();
-    format!("{}/{}", map.part_a(), map.part_b())
+pub fn part_a(input: &str) -> Result<usize, Error> {
+    Map::try_from(input)?.part_a()
+}
+
+#[tracing::instrument(skip(input), fields(day = 10))]
+pub fn part_b(input: &str) -> Result<usize, Error> {
+    Map::try_from(input)?.part_b()
 }
 
 #[tracing::instrument]
-pub fn test() -> (String, String) {
-    let part_a = Map::from(
-        "7-F7-
+pub fn examples() -> Result<Vec<Example>, Error> {
+    Ok(vec![
+        Example::new(
+            "complex loop",
+            "7-F7-
         .FJ|7
         SJLL7
         |F--J
         LJ.LJ",
-    )
-    .part_a();
-
-    let part_b = Map::from(
-        "FF7FSF7F7F7F7F7F---7
+        )
+        .part_a("8"),
+        Example::new(
+            "junk inside",
+            "FF7FSF7F7F7F7F7F---7
         L|LJ||||||||||||F--J
         FL-7LJLJ||||||LJL-77
         F--JF--7||LJLJ7F7FJ-
@@ -192,8 +199,54 @@ pub fn test() -> (String, String) {
         7-L-JL7||F7|L7F-7F7|
         L.L7LFJ|||||FJL7||LJ
         L7JLJL-JLJLJL--JLJ.L",
-    )
-    .part_b();
+        )
+        .part_b("10"),
+    ])
+}
 
-    (format!("{}/{}", part_a, part_b), "8/10".into())
+// a loop round a random tree, drawn at twice the size so there are tiles inside
+// it, with S somewhere on it and junk pipes everywhere else (but not next to S, so
+// it's clear which way S goes)
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const JUNK: &[u8] = b"|-LJ7F..";
+    let nodes = (size as i32 / 4).max(1);
+    let corners = gen::outline(rng, nodes, nodes)
+        .into_iter()
+        .circular_tuple_windows()
+        .flat_map(|(from, to)| [from * 2 + IVec2::ONE, from + to + IVec2::ONE])
+        .collect_vec();
+    let width = 4 * nodes as usize + 1;
+    let mut tiles = (0..width)
+        .map(|_| {
+            (0..width)
+                .map(|_| JUNK[rng.usize(0..JUNK.len())] as char)
+                .collect_vec()
+        })
+        .collect_vec();
+    for (index, &corner) in corners.iter().enumerate() {
+        let prev = corners[(index + corners.len() - 1) % corners.len()] - corner;
+        let next = corners[(index + 1) % corners.len()] - corner;
+        let dirs = [ivec2(0, -1), ivec2(0, 1), ivec2(1, 0), ivec2(-1, 0)]
+            .map(|dir| dir == prev || dir == next);
+        tiles[corner.y as usize][corner.x as usize] = match dirs {
+            [true, true, _, _] => '|',
+            [_, _, true, true] => '-',
+            [true, _, true, _] => 'L',
+            [true, _, _, true] => 'J',
+            [_, true, _, true] => '7',
+            _ => 'F',
+        };
+    }
+    let start = corners[rng.usize(0..corners.len())];
+    for dir in [ivec2(0, -1), ivec2(0, 1), ivec2(1, 0), ivec2(-1, 0)] {
+        let pos = start + dir;
+        if !corners.contains(&pos) {
+            tiles[pos.y as usize][pos.x as usize] = '.';
+        }
+    }
+    tiles[start.y as usize][start.x as usize] = 'S';
+    tiles
+        .iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
 }
@@ -0,0 +1,117 @@
+use anyhow::Error;
+use fastrand::Rng;
+
+use crate::{
+    fixtures::Example,
+    input::{lines, ParseError},
+    Answer, Part,
+};
+
+// each empty row or column becomes this many
+const EXPANSION: [usize; 2] = [2, 1_000_000];
+
+#[tracing::instrument(skip(input), fields(day = 11))]
+pub fn part_a(input: &str) -> Result<usize, Error> {
+    let (galaxies, empties) = read_data(input)?;
+    Ok(calc_dists(&galaxies, &empties, &[EXPANSION[0]])[0])
+}
+
+#[tracing::instrument(skip(input), fields(day = 11))]
+pub fn part_b(input: &str) -> Result<usize, Error> {
+    let (galaxies, empties) = read_data(input)?;
+    Ok(calc_dists(&galaxies, &empties, &[EXPANSION[1]])[0])
+}
+
+// the galaxies, and for each axis how many empty rows or columns come up to each index
+pub type Data = (Vec<[usize; 2]>, [Vec<usize>; 2]);
+
+pub fn read_data(input: &str) -> Result<Data, ParseError> {
+    let mut galaxies = vec![];
+    for line in lines(input) {
+        for (x, ch) in line.text.chars().enumerate() {
+            match ch {
+                '#' => galaxies.push([x, line.number - 1]),
+                '.' => {}
+                _ => return Err(line.error_at(x, format!("unknown space '{}'", ch))),
+            }
+        }
+    }
+
+    let empties = [0, 1].map(|axis| {
+        let mut used = vec![];
+        galaxies.iter().for_each(|galaxy| {
+            if galaxy[axis] >= used.len() {
+                used.resize(galaxy[axis] + 1, false);
+            }
+            used[galaxy[axis]] = true;
+        });
+
+        let mut num_empty = vec![0usize; used.len()];
+        (0..used.len()).for_each(|index| {
+            num_empty[index] = if index == 0 { 0 } else { num_empty[index - 1] }
+                + if !used[index] { 1 } else { 0 };
+        });
+        num_empty
+    });
+
+    Ok((galaxies, empties))
+}
+
+pub fn calc_dists(galaxies: &[[usize; 2]], empties: &[Vec<usize>; 2], mults: &[usize]) -> Vec<usize> {
+    let min_max = |a: usize, b: usize| (a.min(b), a.max(b));
+    let mut total_empties = 0;
+    let mut total_dist = 0;
+    galaxies.iter().enumerate().for_each(|(index, g0)| {
+        galaxies.iter().skip(index + 1).for_each(|g1| {
+            (0..2).for_each(|axis| {
+                let (min, max) = min_max(g0[axis], g1[axis]);
+                total_empties += empties[axis][max] - empties[axis][min];
+                total_dist += max - min;
+            })
+        });
+    });
+    mults
+        .iter()
+        .map(|mult| (mult - 1) * total_empties + total_dist)
+        .collect()
+}
+
+#[tracing::instrument]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "...#......
+    .......#..
+    #.........
+    ..........
+    ......#...
+    .#........
+    .........#
+    ..........
+    .......#..
+    #...#.....";
+    Ok(vec![
+        Example::new("example", input).part_a("374"),
+        Example::new("expansion 10", input)
+            .with_param("expansion", 10)
+            .part_b("1030"),
+        Example::new("expansion 100", input)
+            .with_param("expansion", 100)
+            .part_b("8410"),
+    ])
+}
+
+pub fn solve_example(part: Part, example: &Example) -> Result<Answer, Error> {
+    let (galaxies, empties) = read_data(&example.input)?;
+    let expansion = example.param_or("expansion", EXPANSION[part.index()] as i64);
+    Ok(calc_dists(&galaxies, &empties, &[expansion as usize])[0].into())
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.usize(0..20) == 0 { '#' } else { '.' })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
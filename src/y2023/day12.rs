@@ -0,0 +1,188 @@
+use anyhow::{anyhow, bail, Error};
+use fastrand::Rng;
+use itertools::Itertools;
+
+use crate::{fixtures::Example, input::{non_empty_lines, ParseError}, Part};
+
+type Row = (Vec<u8>, Vec<usize>);
+
+fn parse(input: &str) -> Result<Vec<Row>, ParseError> {
+    non_empty_lines(input)
+        .map(|line| {
+            let (springs, counts) = line.split_once(line.text, " ")?;
+            if let Some((index, ch)) = springs
+                .char_indices()
+                .find(|&(_, ch)| !".#?".contains(ch))
+            {
+                return Err(line.error(&springs[index..], format!("unknown spring '{}'", ch)));
+            }
+            let chars = springs.bytes().collect_vec();
+            let counts = counts
+                .split(',')
+                .map(|s| line.parse::<usize>(s))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok((chars, counts))
+        })
+        .collect()
+}
+
+#[tracing::instrument(skip(input), fields(day = 12))]
+pub fn part_a(input: &str) -> Result<usize, Error> {
+    parse(input)?
+        .into_iter()
+        .map(|(chars, counts)| calc(&chars, &counts))
+        .sum()
+}
+
+#[tracing::instrument(skip(input), fields(day = 12))]
+pub fn part_b(input: &str) -> Result<usize, Error> {
+    parse(input)?
+        .into_iter()
+        .map(|(chars, counts)| {
+            let chars =
+                itertools::Itertools::intersperse([&chars].repeat(5).into_iter(), &vec![b'?'])
+                    .flatten()
+                    .copied()
+                    .collect_vec();
+            let counts = counts.repeat(5);
+            calc(&chars, &counts)
+        })
+        .sum()
+}
+
+const MAX_CHARS: usize = 128;
+const MAX_COUNTS: usize = 32;
+
+fn calc(chars: &[u8], counts: &[usize]) -> Result<usize, Error> {
+    if chars.len() >= MAX_CHARS || counts.len() >= MAX_COUNTS {
+        bail!(
+            "too many springs ({}) or counts ({})",
+            chars.len(),
+            counts.len()
+        );
+    }
+    let mut cache = vec![None; MAX_CHARS * MAX_COUNTS];
+    Ok(inner_calc(chars, counts, &mut cache))
+}
+
+fn inner_calc(chars: &[u8], counts: &[usize], cache: &mut [Option<usize>]) -> usize {
+    if let Some(cached_value) = cache[MAX_COUNTS * chars.len() + counts.len()] {
+        cached_value
+    } else {
+        let is_dot = |ch: &u8| *ch == b'.' || *ch == b'?';
+        let is_dash = |ch: &u8| *ch == b'#' || *ch == b'?';
+        // dots that can go before the first run, none if the runs can't fit at all
+        let spare = counts.iter().try_fold(chars.len() + 1, |spare, &count| {
+            spare.checked_sub(count)?.checked_sub(1)
+        });
+        let value = if counts.is_empty() {
+            if chars.iter().all(is_dot) {
+                1
+            } else {
+                0
+            }
+        } else if let Some(spare) = spare {
+            (0..=spare)
+                .map(|dot_count| {
+                    if chars[0..dot_count].iter().all(is_dot)
+                        && chars[dot_count..(dot_count + counts[0])]
+                            .iter()
+                            .all(is_dash)
+                        && (counts.len() == 1 || is_dot(&chars[dot_count + counts[0]]))
+                    {
+                        inner_calc(
+                            &chars
+                                [(dot_count + counts[0] + if counts.len() > 1 { 1 } else { 0 })..],
+                            &counts[1..],
+                            cache,
+                        )
+                    } else {
+                        0
+                    }
+                })
+                .sum()
+        } else {
+            0
+        };
+        cache[MAX_COUNTS * chars.len() + counts.len()] = Some(value);
+        value
+    }
+}
+
+// places each run in every spot it fits, counting arrangements one at a time with
+// nothing cached. only for rows with up to a few million runs to place between them
+pub fn reference(part: Part, example: &Example) -> Result<usize, Error> {
+    const MAX_PLACED: usize = 1 << 24;
+    fn arrangements(springs: &[u8], counts: &[usize], left: &mut usize) -> Result<usize, Error> {
+        *left = left.checked_sub(1).ok_or_else(|| {
+            anyhow!("too many runs to place for the reference, at most {}", MAX_PLACED)
+        })?;
+        let Some((&count, rest)) = counts.split_first() else {
+            return Ok(springs.iter().all(|&ch| ch != b'#') as usize);
+        };
+        let mut total = 0;
+        for start in 0..springs.len() {
+            // a broken spring before the run would be in a run of its own
+            if start > 0 && springs[start - 1] == b'#' {
+                break;
+            }
+            let end = start + count;
+            if end > springs.len() {
+                break;
+            }
+            if springs[start..end].iter().all(|&ch| ch != b'.') && springs.get(end) != Some(&b'#') {
+                total += arrangements(springs.get(end + 1..).unwrap_or_default(), rest, left)?;
+            }
+        }
+        Ok(total)
+    }
+
+    let mut left = MAX_PLACED;
+    parse(&example.input)?
+        .into_iter()
+        .map(|(springs, counts)| match part {
+            Part::A => arrangements(&springs, &counts, &mut left),
+            Part::B => arrangements(&vec![springs; 5].join(&b'?'), &counts.repeat(5), &mut left),
+        })
+        .sum()
+}
+
+#[tracing::instrument]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "???.### 1,1,3
+        .??..??...?##. 1,1,3
+        ?#?#?#?#?#?#?#? 1,3,1,6
+        ????.#...#... 4,1,1
+        ????.######..#####. 1,6,5
+        ?###???????? 3,2,1";
+    Ok(vec![Example::new("example", input)
+        .part_a("21")
+        .part_b("525152")])
+}
+
+// rows made from their counts then half hidden, so each has an arrangement. kept
+// short enough to still fit once unfolded for part b
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let (counts, springs) = loop {
+                let counts = (0..rng.usize(1..=5))
+                    .map(|_| rng.usize(1..=4))
+                    .collect_vec();
+                let mut springs = ".".repeat(rng.usize(0..3));
+                for count in counts.iter() {
+                    springs.push_str(&"#".repeat(*count));
+                    springs.push_str(&".".repeat(rng.usize(1..3)));
+                }
+                if springs.len() * 5 + 4 < MAX_CHARS {
+                    break (counts, springs);
+                }
+            };
+            let springs = springs
+                .chars()
+                .map(|ch| if rng.bool() { '?' } else { ch })
+                .collect::<String>();
+            format!("{} {}\n", springs, counts.iter().join(","))
+        })
+        .collect()
+}
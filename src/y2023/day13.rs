@@ -0,0 +1,164 @@
+use anyhow::Error;
+use fastrand::Rng;
+use itertools::Itertools;
+
+use crate::{fixtures::Example, input::{lines, ParseError}};
+
+#[tracing::instrument(skip(input), fields(day=13))]
+pub fn part_a(input: &str) -> Result<usize, Error> {
+    Ok(summarize(&parse(input)?, 0))
+}
+
+#[tracing::instrument(skip(input), fields(day=13))]
+pub fn part_b(input: &str) -> Result<usize, Error> {
+    Ok(summarize(&parse(input)?, 1))
+}
+
+// each pattern (split by blank lines) as the bits of its rows and of its columns
+fn parse(input: &str) -> Result<Vec<[Vec<u64>; 2]>, ParseError> {
+    let mut patterns = vec![[vec![], vec![]]];
+    for line in lines(input) {
+        let [rows, columns] = patterns.last_mut().unwrap();
+        if line.text.is_empty() {
+            if !rows.is_empty() {
+                patterns.push([vec![], vec![]]);
+            }
+            continue;
+        }
+        if line.text.len() > 64 || rows.len() == 64 {
+            return Err(line.error_at(0, "patterns can be at most 64 by 64"));
+        }
+        if !rows.is_empty() && line.text.len() != columns.len() {
+            return Err(line.error_at(0, format!("expected {} columns", columns.len())));
+        }
+        columns.resize(line.text.len(), 0);
+        let mut row = 0;
+        for (x, ch) in line.text.chars().enumerate() {
+            let num = match ch {
+                '#' => 1,
+                '.' => 0,
+                _ => return Err(line.error_at(x, format!("unknown ground '{}'", ch))),
+            };
+            row = row * 2 + num;
+            columns[x] = columns[x] * 2 + num;
+        }
+        rows.push(row);
+    }
+    patterns.retain(|[rows, _]| !rows.is_empty());
+    Ok(patterns)
+}
+
+fn summarize(patterns: &[[Vec<u64>; 2]], num_wrong_bits: u32) -> usize {
+    patterns.iter().map(|[rows, columns]| {
+        100 * find(rows, num_wrong_bits).unwrap_or(0) + find(columns, num_wrong_bits).unwrap_or(0)
+    }).sum()
+}
+
+fn find(vals: &[u64], num_wrong_bits: u32) -> Option<usize> {
+    (1..vals.len()).find(|&index| {
+        (0..(index.min(vals.len() - index))).map(|cmp| {
+            (vals[index - 1 - cmp] ^ vals[index + cmp]).count_ones()
+        }).sum::<u32>() == num_wrong_bits
+    })
+}
+
+#[tracing::instrument]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "#.##..##.
+        ..#.##.#.
+        ##......#
+        ##......#
+        ..#.##.#.
+        ..##..##.
+        #.#.##.#.
+
+        #...##..#
+        #....#..#
+        ..##..###
+        #####.##.
+        #####.##.
+        ..##..###
+        #....#..#";
+    Ok(vec![Example::new("example", input)
+        .part_a("405")
+        .part_b("400")])
+}
+
+// patterns with one perfect line of reflection and one other line that's a smudge
+// away from being one. the smudge is in a row the perfect line doesn't reach
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    // the differences across each line between rows
+    let differences = |rows: &[Vec<bool>]| {
+        (1..rows.len())
+            .map(|line| {
+                (0..line.min(rows.len() - line))
+                    .map(|offset| {
+                        rows[line - 1 - offset]
+                            .iter()
+                            .zip(rows[line + offset].iter())
+                            .filter(|(a, b)| a != b)
+                            .count()
+                    })
+                    .sum::<usize>()
+            })
+            .collect_vec()
+    };
+    let transpose = |rows: &[Vec<bool>]| {
+        (0..rows[0].len())
+            .map(|x| rows.iter().map(|row| row[x]).collect_vec())
+            .collect_vec()
+    };
+    (0..size)
+        .map(|_| loop {
+            let (width, height) = (rng.usize(5..=15), rng.usize(5..=15));
+            let row_line = rng.usize(1..height);
+            if row_line * 2 == height {
+                continue;
+            }
+            let column_line = rng.usize(1..width);
+            let mut rows = (0..height)
+                .map(|_| (0..width).map(|_| rng.bool()).collect_vec())
+                .collect_vec();
+            let row_reach = row_line.min(height - row_line);
+            for offset in 0..row_reach {
+                rows[row_line + offset] = rows[row_line - 1 - offset].clone();
+            }
+            let column_reach = column_line.min(width - column_line);
+            for row in rows.iter_mut() {
+                for offset in 0..column_reach {
+                    row[column_line + offset] = row[column_line - 1 - offset];
+                }
+            }
+            let y = if row_line * 2 < height {
+                rng.usize(row_line * 2..height)
+            } else {
+                rng.usize(0..height - row_reach * 2)
+            };
+            let x = rng.usize(column_line - column_reach..column_line + column_reach);
+            rows[y][x] = !rows[y][x];
+
+            // other lines can come out as reflections by chance
+            let (across_rows, across_columns) = (differences(&rows), differences(&transpose(&rows)));
+            let count = |differences: &[usize], wanted| {
+                differences.iter().filter(|&&count| count == wanted).count()
+            };
+            if count(&across_rows, 0) + count(&across_columns, 0) != 1
+                || count(&across_rows, 1) + count(&across_columns, 1) != 1
+            {
+                continue;
+            }
+            if rng.bool() {
+                rows = transpose(&rows);
+            }
+            break rows
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|&rock| if rock { '#' } else { '.' })
+                        .collect::<String>()
+                        + "\n"
+                })
+                .collect::<String>();
+        })
+        .join("\n")
+}
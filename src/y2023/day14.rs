@@ -0,0 +1,243 @@
+use anyhow::Error;
+use fastrand::Rng;
+use glam::{ivec2, IVec2};
+use itertools::Itertools;
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
+use std::hash::Hasher;
+
+use crate::{
+    fixtures::Example,
+    input::{grid, lines, ParseError},
+    Part,
+};
+
+struct Platform {
+    size: IVec2,
+    rolls: Vec<IVec2>,
+    // for each tilt direction, where a roll at each position ends up against
+    grids: [Vec<IVec2>; 4],
+}
+
+fn parse(input: &str) -> Result<Platform, ParseError> {
+    let mut rocks = FxHashSet::default();
+    let mut rolls = vec![];
+    let mut size = IVec2::ZERO;
+    for line in lines(input) {
+        let y = line.number - 1;
+        size.y = size.y.max(y as i32 + 1);
+        // rows get hashed as u128 bitmasks
+        if line.text.len() > 128 {
+            return Err(line.error_at(128, "platform can be at most 128 wide"));
+        }
+        for (x, ch) in line.text.chars().enumerate() {
+            size.x = size.x.max(x as i32 + 1);
+            let v = ivec2(x as i32, y as i32);
+            match ch {
+                '#' => {
+                    rocks.insert(v);
+                }
+                'O' => rolls.push(v),
+                '.' => {}
+                _ => return Err(line.error_at(x, format!("unknown rock '{}'", ch))),
+            }
+        }
+    }
+
+    let mut grids: [Vec<IVec2>; 4] = (0..4)
+        .map(|_| vec![IVec2::ZERO; (size.x * size.y) as usize])
+        .collect_vec()
+        .try_into()
+        .unwrap();
+    for x in 0..size.x {
+        let mut write = ivec2(x, 0);
+        for y in 0..size.y {
+            if rocks.contains(&ivec2(x, y)) {
+                write = ivec2(x, y + 1);
+            }
+            grids[0][(y * size.x + x) as usize] = write;
+        }
+        let mut write = ivec2(x, size.y - 1);
+        for y in (0..size.y).rev() {
+            if rocks.contains(&ivec2(x, y)) {
+                write = ivec2(x, y - 1);
+            }
+            grids[2][(y * size.x + x) as usize] = write;
+        }
+    }
+    for y in 0..size.y {
+        let mut write = ivec2(0, y);
+        for x in 0..size.x {
+            if rocks.contains(&ivec2(x, y)) {
+                write = ivec2(x + 1, y);
+            }
+            grids[1][(y * size.x + x) as usize] = write;
+        }
+        let mut write = ivec2(size.x - 1, y);
+        for x in (0..size.x).rev() {
+            if rocks.contains(&ivec2(x, y)) {
+                write = ivec2(x - 1, y);
+            }
+            grids[3][(y * size.x + x) as usize] = write;
+        }
+    }
+
+    Ok(Platform { size, rolls, grids })
+}
+
+#[tracing::instrument(skip(input), fields(day = 14))]
+pub fn part_a(input: &str) -> Result<i32, Error> {
+    let Platform {
+        size,
+        mut rolls,
+        grids,
+    } = parse(input)?;
+    shake(&size, &mut rolls, &grids[0], IVec2::Y);
+    Ok(score(&size, &rolls))
+}
+
+#[tracing::instrument(skip(input), fields(day = 14))]
+pub fn part_b(input: &str) -> Result<i32, Error> {
+    let Platform {
+        size,
+        mut rolls,
+        grids,
+    } = parse(input)?;
+    let mut hashes = FxHashMap::<u64, usize>::default();
+    let mut scores = vec![];
+    let mut part_b = None;
+    let mut storage = vec![0u128; size.y as usize];
+    while part_b.is_none() {
+        for (index, grid) in grids.iter().enumerate() {
+            shake(
+                &size,
+                &mut rolls,
+                grid,
+                [IVec2::Y, IVec2::X, IVec2::NEG_Y, IVec2::NEG_X][index],
+            );
+        }
+        let hash = hash(&mut storage, &rolls);
+        if let Some(start_loop) = hashes.get(&hash) {
+            part_b = Some(
+                scores[start_loop
+                    + ((1_000_000_000 - 1) - start_loop) % (scores.len() - start_loop)],
+            );
+            break;
+        }
+        hashes.insert(hash, scores.len());
+        scores.push(score(&size, &rolls));
+    }
+
+    Ok(part_b.unwrap())
+}
+
+fn shake(size: &IVec2, rolls: &mut [IVec2], grid: &[IVec2], dir: IVec2) {
+    let mut used = vec![0i32; (size.x * size.y).try_into().unwrap()];
+    for roll in rolls.iter_mut() {
+        let nearest_rock = grid[(roll.y * size.x + roll.x) as usize];
+        let used_index = (nearest_rock.y * size.x + nearest_rock.x) as usize;
+        let used_entry = used.get_mut(used_index).unwrap();
+        let new_roll = nearest_rock + dir * *used_entry;
+        *used_entry += 1;
+        *roll = new_roll;
+    }
+}
+
+fn score(size: &IVec2, rolls: &[IVec2]) -> i32 {
+    rolls.iter().map(|roll| size.y - roll.y).sum::<i32>()
+}
+
+fn hash(storage_for_rows: &mut [u128], rolls: &[IVec2]) -> u64 {
+    for roll in rolls {
+        storage_for_rows[roll.y as usize] |= 1 << (roll.x as usize);
+    }
+    let mut hasher = FxHasher::default();
+    for row in storage_for_rows.iter_mut() {
+        hasher.write_u128(*row);
+        *row = 0;
+    }
+    hasher.finish()
+}
+
+// rolls each rock a step at a time until none move, and finds the loop by comparing
+// whole platforms rather than hashes
+pub fn reference(part: Part, example: &Example) -> Result<i32, Error> {
+    let mut platform = grid(&example.input, |ch| "O#.".contains(ch).then_some(ch))?;
+    let tilt = |platform: &mut Vec<Vec<char>>, dir: IVec2| loop {
+        let mut moved = false;
+        for y in 0..platform.len() {
+            for x in 0..platform[y].len() {
+                let to = ivec2(x as i32, y as i32) + dir;
+                let free = platform
+                    .get(to.y as usize)
+                    .and_then(|row| row.get(to.x as usize))
+                    == Some(&'.');
+                if platform[y][x] == 'O' && free {
+                    platform[y][x] = '.';
+                    platform[to.y as usize][to.x as usize] = 'O';
+                    moved = true;
+                }
+            }
+        }
+        if !moved {
+            break;
+        }
+    };
+    let load = |platform: &Vec<Vec<char>>| {
+        platform
+            .iter()
+            .enumerate()
+            .map(|(y, row)| (platform.len() - y) as i32 * row.iter().filter(|&&ch| ch == 'O').count() as i32)
+            .sum::<i32>()
+    };
+    if part == Part::A {
+        tilt(&mut platform, IVec2::NEG_Y);
+        return Ok(load(&platform));
+    }
+    let mut seen = FxHashMap::default();
+    let mut loads = vec![];
+    for cycle in 0usize.. {
+        if let Some(&start) = seen.get(&platform) {
+            return Ok(loads[start + (1_000_000_000 - start) % (cycle - start)]);
+        }
+        seen.insert(platform.clone(), cycle);
+        loads.push(load(&platform));
+        for dir in [IVec2::NEG_Y, IVec2::NEG_X, IVec2::Y, IVec2::X] {
+            tilt(&mut platform, dir);
+        }
+    }
+    unreachable!()
+}
+
+#[tracing::instrument]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "O....#....
+        O.OO#....#
+        .....##...
+        OO.#O....O
+        .O.....O#.
+        O.#..O.#.#
+        ..O..#O..O
+        .......O..
+        #....###..
+        #OO..#....";
+    Ok(vec![Example::new("example", input)
+        .part_a("136")
+        .part_b("64")])
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    // the widest platform that fits the bitmasks
+    let size = size.min(128);
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| match rng.usize(0..10) {
+                    0..=1 => 'O',
+                    2 => '#',
+                    _ => '.',
+                })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
@@ -0,0 +1,110 @@
+use anyhow::Error;
+use fastrand::Rng;
+use itertools::Itertools;
+use rustc_hash::FxHashSet;
+
+use crate::{fixtures::Example, gen, input::{non_empty_lines, unexpected_end, ParseError}};
+
+// each step, its label, and the focal length it sets (none to remove the lens)
+type Step<'a> = (&'a str, &'a str, Option<u32>);
+
+fn parse(input: &str) -> Result<Vec<Step<'_>>, ParseError> {
+    let line = non_empty_lines(input)
+        .next()
+        .ok_or_else(|| unexpected_end(input, "expected steps"))?;
+    line.text
+        .split(',')
+        .map(|step| {
+            if let Some(label) = step.strip_suffix('-') {
+                return Ok((step, label, None));
+            }
+            let (label, num) = line.split_once(step, "=")?;
+            Ok((step, label, Some(line.parse(num)?)))
+        })
+        .collect()
+}
+
+#[tracing::instrument(skip(input), fields(day = 15))]
+pub fn part_a(input: &str) -> Result<u32, Error> {
+    Ok(parse(input)?
+        .iter()
+        .map(|(step, _, _)| hash(step) as u32)
+        .sum::<u32>())
+}
+
+#[tracing::instrument(skip(input), fields(day = 15))]
+pub fn part_b(input: &str) -> Result<usize, Error> {
+    Ok(focusing_power(&parse(input)?))
+}
+
+fn set_box(hash_boxes: &mut [Vec<(String, u32)>], id: &str, val: u32) {
+    let hash_box = hash_boxes.get_mut(hash(id) as usize).unwrap();
+    if let Some(index) = hash_box.iter_mut().position(|v| v.0 == id) {
+        hash_box[index].1 = val;
+    } else {
+        hash_box.push((id.into(), val));
+    }
+}
+
+fn dec_box(hash_boxes: &mut [Vec<(String, u32)>], id: &str) {
+    let hash_box = hash_boxes.get_mut(hash(id) as usize).unwrap();
+    if let Some(index) = hash_box.iter_mut().position(|val| val.0 == id) {
+        hash_box.remove(index);
+    }
+}
+
+fn focusing_power(seq: &[Step]) -> usize {
+    let mut hash_boxes = (0..256).map(|_| Vec::<(String, u32)>::default()).collect_vec();
+    for &(_, id, num) in seq {
+        match num {
+            None => {
+                dec_box(&mut hash_boxes, id);
+                tracing::debug!("- {}", id);
+            }
+            Some(num) => {
+                set_box(&mut hash_boxes, id, num);
+                tracing::debug!("= {} {}", id, num);
+            }
+        }
+    }
+    hash_boxes.iter().enumerate().map(|(index, hash_box)| {
+        (1 + index) * hash_box.iter().enumerate().map(|(entry_index, entry)| {
+            (1 + entry_index) * (entry.1 as usize)
+        }).sum::<usize>()
+    }).sum::<usize>()
+}
+
+fn hash(s: &str) -> u8 {
+    s.chars()
+        .fold(0, |agg, ch| ((agg + (ch as u32)) * 17) % 256) as u8
+}
+
+#[tracing::instrument]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+    Ok(vec![Example::new("example", input)
+        .part_a("1320")
+        .part_b("145")])
+}
+
+// steps from a small pool of labels, so lenses get replaced and taken out again
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut taken = FxHashSet::default();
+    let labels = (0..size.div_ceil(4))
+        .map(|_| {
+ let len = rng.usize(2..=6);
+ gen::name(rng, len, &mut taken)
+})
+        .collect_vec();
+    (0..size)
+        .map(|_| {
+            let label = &labels[rng.usize(0..labels.len())];
+            if rng.usize(0..3) == 0 {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.u32(1..=9))
+            }
+        })
+        .join(",")
+        + "\n"
+}
@@ -1,26 +1,31 @@
+use anyhow::Error;
+use fastrand::Rng;
 use glam::{ivec2, IVec2};
-use itertools::Itertools;
 
-#[tracing::instrument(skip(input), fields(day = 16))]
-pub fn solve(input: &str) -> String {
-    let map = input
-        .lines()
-        .map(|line| line.trim().chars().collect_vec())
-        .collect_vec();
+use crate::{fixtures::Example, input::{grid, ParseError}};
+
+fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let map = grid(input, |ch| "./\\|-".contains(ch).then_some(ch))?;
     tracing::debug!("{:?}", map);
 
     for row in map.iter() {
         tracing::debug!("{}", row.iter().collect::<String>());
     }
 
-    let part_a = send_beam(&map, IVec2::ZERO, 0);
+    Ok(map)
+}
 
-    let part_b = part_b(&map);
+#[tracing::instrument(skip(input), fields(day = 16))]
+pub fn part_a(input: &str) -> Result<usize, Error> {
+    Ok(send_beam(&parse(input)?, IVec2::ZERO, 0))
+}
 
-    format!("{}/{}", part_a, part_b)
+#[tracing::instrument(skip(input), fields(day = 16))]
+pub fn part_b(input: &str) -> Result<usize, Error> {
+    Ok(max_energized(&parse(input)?))
 }
 
-fn part_b(map: &[Vec<char>]) -> usize {
+fn max_energized(map: &[Vec<char>]) -> usize {
     let mut max_count = 0;
     for y in 0..map.len() as i32 {
         max_count = max_count.max(send_beam(map, ivec2(0, y), 0));
@@ -74,10 +79,8 @@ fn send_beam(map: &[Vec<char>], pos: IVec2, dir_index: u8) -> usize {
 }
 
 #[tracing::instrument]
-pub fn test() -> (String, String) {
-    (
-        solve(
-            r".|...\....
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = r".|...\....
         |.-.\.....
         .....|-...
         ........|.
@@ -86,8 +89,23 @@ pub fn test() -> (String, String) {
         ..../.\\..
         .-.-/..|..
         .|....-|.\
-        ..//.|....",
-        ),
-        "46/51".into(),
-    )
+        ..//.|....";
+    Ok(vec![Example::new("example", input)
+        .part_a("46")
+        .part_b("51")])
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const MIRRORS: &[u8] = b"/\\|-";
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| match rng.usize(0..10) {
+                    0 => MIRRORS[rng.usize(0..MIRRORS.len())] as char,
+                    _ => '.',
+                })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
 }
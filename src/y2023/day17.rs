@@ -1,26 +1,24 @@
+use anyhow::Error;
+use fastrand::Rng;
 use glam::{ivec2, IVec2};
-use itertools::Itertools;
 use std::collections::BinaryHeap;
 
-#[tracing::instrument(skip(input), fields(day = 17))]
-pub fn solve(input: &str) -> String {
-    let grid = input
-        .lines()
-        .map(|line| {
-            line.trim()
-                .chars()
-                .map(|ch| ch.to_digit(10).unwrap() as u8)
-                .collect_vec()
-        })
-        .collect_vec();
+use crate::{fixtures::Example, input::{grid, ParseError}};
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    let grid = grid(input, |ch| ch.to_digit(10).map(|digit| digit as u8))?;
     let size = ivec2(grid[0].len() as i32, grid.len() as i32);
-    let map = Map { grid, size };
+    Ok(Map { grid, size })
+}
 
-    format!(
-        "{}/{}",
-        expand_routes::<1, 3>(&map),
-        expand_routes::<4, 10>(&map)
-    )
+#[tracing::instrument(skip(input), fields(day = 17))]
+pub fn part_a(input: &str) -> Result<u32, Error> {
+    Ok(expand_routes::<1, 3>(&parse(input)?))
+}
+
+#[tracing::instrument(skip(input), fields(day = 17))]
+pub fn part_b(input: &str) -> Result<u32, Error> {
+    Ok(expand_routes::<4, 10>(&parse(input)?))
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -39,7 +37,7 @@ impl PartialEq for Route {
 impl Eq for Route {}
 impl PartialOrd for Route {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.score.partial_cmp(&other.score).map(|p| p.reverse())
+        Some(self.cmp(other))
     }
 }
 impl Ord for Route {
@@ -48,7 +46,7 @@ impl Ord for Route {
     }
 }
 
-fn expand_routes<const MIN: u32, const MAX: u32>(map: &Map) -> u32 {
+pub fn expand_routes<const MIN: u32, const MAX: u32>(map: &Map) -> u32 {
     let target = map.size - IVec2::ONE;
     let score =
         |cost: u32, pos: IVec2| cost + ((pos.x - target.x).abs() + (pos.y - target.y).abs()) as u32;
@@ -109,7 +107,7 @@ fn expand_routes<const MIN: u32, const MAX: u32>(map: &Map) -> u32 {
         .unwrap()
 }
 
-struct Map {
+pub struct Map {
     grid: Vec<Vec<u8>>,
     size: IVec2,
 }
@@ -125,10 +123,8 @@ impl Map {
 }
 
 #[tracing::instrument]
-pub fn test() -> (String, String) {
-    (
-        solve(
-            "2413432311323
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "2413432311323
         3215453535623
         3255245654254
         3446585845452
@@ -140,8 +136,14 @@ pub fn test() -> (String, String) {
         4564679986453
         1224686865563
         2546548887735
-        4322674655533",
-        ),
-        "102/94".into(),
-    )
+        4322674655533";
+    Ok(vec![Example::new("example", input)
+        .part_a("102")
+        .part_b("94")])
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| (0..size).map(|_| rng.char('1'..='9')).collect::<String>() + "\n")
+        .collect()
 }
@@ -0,0 +1,260 @@
+use anyhow::Error;
+use fastrand::Rng;
+use glam::{ivec2, IVec2};
+use itertools::Itertools;
+use rustc_hash::FxHashMap;
+
+use crate::{fixtures::Example, gen, input::{non_empty_lines, unexpected_end, ParseError}};
+
+struct XAndLen {
+    x: i32,
+    len: i32,
+}
+
+impl XAndLen {
+    fn next(&self, gap: i32) -> Option<Self> {
+        if self.len > gap {
+            Some(XAndLen {
+                x: self.x,
+                len: self.len - gap,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+struct PosAndLen {
+    pos: IVec2,
+    len: i32,
+}
+
+impl From<IVec2> for PosAndLen {
+    fn from(pos: IVec2) -> Self {
+        PosAndLen { pos, len: 1 }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct DirAndLen {
+    dir: IVec2,
+    len: i32,
+}
+
+fn parse(input: &str) -> Result<Vec<[DirAndLen; 2]>, ParseError> {
+    let dirs = [
+        ("R", IVec2::X),
+        ("L", -IVec2::X),
+        ("D", IVec2::Y),
+        ("U", -IVec2::Y),
+    ]
+    .into_iter()
+    .collect::<FxHashMap<&str, IVec2>>();
+
+    let mut lines = non_empty_lines(input)
+        .map(|line| {
+            let (dir, rest) = line.split_once(line.text, " ")?;
+            let (len, colour) = line.split_once(rest, " ")?;
+            let part_a = DirAndLen {
+                dir: *dirs
+                    .get(dir)
+                    .ok_or_else(|| line.error(dir, format!("unknown direction '{}'", dir)))?,
+                len: line.parse(len)?,
+            };
+            if part_a.len <= 0 {
+                return Err(line.error(len, "expected a length above 0"));
+            }
+
+            // the colour is really five hex digits of length then a direction digit
+            let hex = line.strip_suffix(line.strip_prefix(colour, "(#")?, ")")?;
+            if hex.len() != 6 || !hex.chars().all(|ch| ch.is_ascii_hexdigit()) {
+                return Err(line.error(hex, "expected 6 hex digits"));
+            }
+            let part_b = DirAndLen {
+                len: i32::from_str_radix(&hex[0..5], 16).map_err(|err| line.error(hex, err))?,
+                dir: match &hex[5..] {
+                    "0" => IVec2::X,
+                    "1" => IVec2::Y,
+                    "2" => -IVec2::X,
+                    "3" => -IVec2::Y,
+                    _ => return Err(line.error(&hex[5..], "expected a direction from 0 to 3")),
+                },
+            };
+            if part_b.len <= 0 {
+                return Err(line.error(hex, "expected a length above 0"));
+            }
+            Ok([part_a, part_b])
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if lines.len() < 2 {
+        return Err(unexpected_end(input, "expected at least 2 digs"));
+    }
+    // area only works on a loop, for both the lengths and the colours
+    for (index, name) in ["lengths", "colours"].into_iter().enumerate() {
+        let (x, y) = lines.iter().fold((0i64, 0i64), |(x, y), dig| {
+            let dig = dig[index];
+            (
+                x + (dig.dir.x * dig.len) as i64,
+                y + (dig.dir.y * dig.len) as i64,
+            )
+        });
+        if (x, y) != (0, 0) {
+            return Err(unexpected_end(
+                input,
+                format!("expected the {name} to dig back to the start, but they end at {x}, {y}"),
+            ));
+        }
+    }
+    lines.push(lines[0]);
+    lines.push(lines[1]);
+    Ok(lines)
+}
+
+#[tracing::instrument(skip(input), fields(day = 18))]
+pub fn part_a(input: &str) -> Result<usize, Error> {
+    Ok(area(&parse(input)?, 0))
+}
+
+#[tracing::instrument(skip(input), fields(day = 18))]
+pub fn part_b(input: &str) -> Result<usize, Error> {
+    Ok(area(&parse(input)?, 1))
+}
+
+fn area(lines: &[[DirAndLen; 2]], index: usize) -> usize {
+    let mut pos = ivec2(0, 0);
+    let mut edges: Vec<PosAndLen> = vec![];
+    for window in lines.windows(3) {
+        let (prev, line, next) = (window[0][index], window[1][index], window[2][index]);
+        if line.dir.y != 0 {
+            edges.push(PosAndLen {
+                pos: pos + IVec2::Y * if line.dir.y > 0 { 1 } else { 1 - line.len },
+                len: line.len - 1,
+            });
+            pos += line.len * line.dir;
+        } else {
+            if prev.dir.y != line.dir.x {
+                edges.push(pos.into());
+            }
+            pos += line.len * line.dir;
+            if next.dir.y == line.dir.x {
+                edges.push(pos.into());
+            }
+        }
+    }
+    edges.sort_by_key(|edge| edge.pos.y);
+
+    let mut tracked_edges: Vec<XAndLen> = vec![];
+    let mut edge_index = 0;
+    let mut area = 0;
+    let mut y = edges[0].pos.y;
+    loop {
+        // add edges matching y
+        let mut added = false;
+        while edge_index < edges.len() && edges[edge_index].pos.y == y {
+            tracked_edges.push(XAndLen {
+                x: edges[edge_index].pos.x,
+                len: edges[edge_index].len,
+            });
+            edge_index += 1;
+            added = true;
+        }
+        // sort if added
+        if added {
+            tracked_edges.sort_by_key(|x_and_len| x_and_len.x);
+        }
+        if tracked_edges.is_empty() {
+            break;
+        }
+        // see if can skip
+        let gap_until_next_edge = tracked_edges
+            .iter()
+            .map(|x_and_len| x_and_len.len)
+            .min()
+            .unwrap()
+            .min(if edge_index < edges.len() {
+                edges[edge_index].pos.y - y
+            } else {
+                1
+            });
+        // add area
+        area += (gap_until_next_edge as usize)
+            * tracked_edges
+                .chunks(2)
+                .map(|pair| (1 + pair[1].x - pair[0].x) as usize)
+                .sum::<usize>();
+        // remove any redundant edges
+        tracked_edges = tracked_edges
+            .into_iter()
+            .filter_map(|x_and_len| x_and_len.next(gap_until_next_edge))
+            .collect_vec();
+        y += gap_until_next_edge;
+    }
+    area
+}
+
+#[tracing::instrument]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "R 6 (#70c710)
+        D 5 (#0dc571)
+        L 2 (#5713f0)
+        D 2 (#d2c081)
+        R 2 (#59c680)
+        D 2 (#411b91)
+        L 5 (#8ceee2)
+        U 2 (#caa173)
+        L 1 (#1b58a2)
+        U 2 (#caa171)
+        R 2 (#7807d2)
+        U 3 (#a77fa3)
+        L 2 (#015232)
+        U 2 (#7a21e3)";
+    Ok(vec![Example::new("example", input)
+        .part_a("62")
+        .part_b("952408144115")])
+}
+
+// a loop that never touches itself, stretched by random amounts for part a and
+// stretched far more for the colours
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let nodes = (size as i32 / 4).max(1);
+    let corners = gen::outline(rng, nodes, nodes);
+    // each column and row of the outline gets its own length, so the stretched
+    // loop still doesn't touch itself. no dig is a single metre, as in the real
+    // inputs
+    let stretch = |rng: &mut Rng, most: i32| {
+        let lens = [(); 2].map(|_| (0..2 * nodes).map(|_| rng.i32(2..=most)).collect_vec());
+        corners
+            .iter()
+            .circular_tuple_windows()
+            .map(|(&from, &to)| match to - from {
+                dir if dir.x != 0 => lens[0][from.x.min(to.x) as usize],
+                _ => lens[1][from.y.min(to.y) as usize],
+            })
+            .collect_vec()
+    };
+    let (part_a, part_b) = (stretch(rng, 10), stretch(rng, 0xFFFFF / (2 * nodes)));
+    corners
+        .iter()
+        .circular_tuple_windows()
+        .map(|(&from, &to)| to - from)
+        .zip(part_a.into_iter().zip(part_b))
+        // runs in the same direction are one dig
+        .coalesce(|(dir, (a, b)), (next_dir, (next_a, next_b))| {
+            if dir == next_dir {
+                Ok((dir, (a + next_a, b + next_b)))
+            } else {
+                Err(((dir, (a, b)), (next_dir, (next_a, next_b))))
+            }
+        })
+        .map(|(dir, (len, hex_len))| {
+            let (letter, digit) = match (dir.x, dir.y) {
+                (1, _) => ('R', 0),
+                (_, 1) => ('D', 1),
+                (-1, _) => ('L', 2),
+                _ => ('U', 3),
+            };
+            format!("{letter} {len} (#{hex_len:05x}{digit})\n")
+        })
+        .collect()
+}
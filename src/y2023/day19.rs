@@ -0,0 +1,289 @@
+use std::ops::RangeInclusive;
+
+use anyhow::{anyhow, Error};
+use fastrand::Rng;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{fixtures::Example, gen, input::{lines, Line, ParseError}};
+
+#[derive(Debug, Copy, Clone)]
+enum Test {
+    Greater(usize, u32),
+    Less(usize, u32),
+    Always,
+}
+
+impl Test {
+    fn reverse(&self) -> Self {
+        match self {
+            Test::Greater(index, val) => Test::Less(*index, val + 1),
+            Test::Less(index, val) => Test::Greater(*index, val - 1),
+            Test::Always => panic!(),
+        }
+    }
+}
+
+type Goto<'target> = (Test, &'target str);
+type Instructions<'name, 'target> = FxHashMap<&'name str, Vec<Goto<'target>>>;
+
+fn parse(input: &str) -> Result<(Instructions<'_, '_>, Vec<[u32; 4]>), ParseError> {
+    // workflows, then a blank line, then part ratings
+    let mut lines = lines(input).skip_while(|line| line.text.is_empty());
+
+    let insts = lines
+        .by_ref()
+        .take_while(|line| !line.text.is_empty())
+        .map(|line| {
+            let (name, commands) = line.split_once(line.text, "{")?;
+            let commands = line.strip_suffix(commands, "}")?;
+            let mut commands = commands
+                .split(',')
+                .map(|command| parse_command(&line, command))
+                .collect::<Result<Vec<_>, _>>()?;
+            if let Some(index) = commands
+                .iter()
+                .position(|(test, _)| matches!(test, Test::Always))
+            {
+                if index != commands.len() - 1 {
+                    return Err(line.error(commands[index + 1].1, "rule after an unconditional one"));
+                }
+            } else {
+                return Err(line.error_at(
+                    line.text.len() - 1,
+                    "expected a rule without a condition last",
+                ));
+            }
+
+            // simplify commands
+            while commands.len() >= 2
+                && commands[commands.len() - 2].1 == commands[commands.len() - 1].1
+            {
+                commands.remove(commands.len() - 2);
+            }
+
+            Ok((name, commands))
+        })
+        .collect::<Result<Instructions, ParseError>>()?;
+
+    let values = lines
+        .filter(|line| !line.text.is_empty())
+        .map(|line| {
+            let ratings = line.strip_suffix(line.strip_prefix(line.text, "{")?, "}")?;
+            let mut ratings = ratings.split(',');
+            let mut value = [0; 4];
+            for (index, name) in ["x=", "m=", "a=", "s="].into_iter().enumerate() {
+                let rating = ratings.next().ok_or_else(|| {
+                    line.error_at(line.text.len() - 1, format!("expected '{}'", name))
+                })?;
+                value[index] = line.parse(line.strip_prefix(rating, name)?)?;
+            }
+            Ok(value)
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+
+    Ok((insts, values))
+}
+
+// a rule like "a<2006:qkq", or just where to go
+fn parse_command<'a>(line: &Line<'a>, command: &'a str) -> Result<Goto<'a>, ParseError> {
+    let Some((test, target)) = command.split_once(':') else {
+        return Ok((Test::Always, command));
+    };
+    let var = test
+        .chars()
+        .next()
+        .and_then(|ch| "xmas".find(ch))
+        .ok_or_else(|| line.error(test, "expected 'x', 'm', 'a' or 's'"))?;
+    let rest = &test[1..];
+    let (test, val) = if let Some(val) = rest.strip_prefix('>') {
+        (Test::Greater(var, line.parse(val)?), val)
+    } else if let Some(val) = rest.strip_prefix('<') {
+        (Test::Less(var, line.parse(val)?), val)
+    } else {
+        return Err(line.error(rest, "expected '<' or '>'"));
+    };
+    match test {
+        Test::Greater(_, 1..=4000) | Test::Less(_, 1..=4000) => Ok((test, target)),
+        _ => Err(line.error(val, "expected a rating from 1 to 4000")),
+    }
+}
+
+#[tracing::instrument(skip(input), fields(day = 19))]
+pub fn part_a(input: &str) -> Result<u32, Error> {
+    let (insts, values) = parse(input)?;
+    accepted_ratings(&insts, &values)
+}
+
+#[tracing::instrument(skip(input), fields(day = 19))]
+pub fn part_b(input: &str) -> Result<u64, Error> {
+    Ok(accepted_combinations(&parse(input)?.0))
+}
+
+fn accepted_ratings(insts: &Instructions, values: &[[u32; 4]]) -> Result<u32, Error> {
+    values
+        .iter()
+        .map(|value| {
+            let mut pos = "in";
+            loop {
+                if pos == "A" {
+                    return Ok(value.iter().sum::<u32>());
+                } else if pos == "R" {
+                    return Ok(0);
+                } else {
+                    // every workflow ends with a rule that always matches
+                    pos = insts
+                        .get(&pos)
+                        .ok_or_else(|| anyhow!("no workflow '{}'", pos))?
+                        .iter()
+                        .find(|command| match command.0 {
+                            Test::Always => true,
+                            Test::Greater(index, val) => value[index] > val,
+                            Test::Less(index, val) => value[index] < val,
+                        })
+                        .unwrap()
+                        .1;
+                }
+            }
+        })
+        .sum()
+}
+
+type Xmas = [RangeInclusive<u32>; 4];
+type Cache<'name> = FxHashMap<&'name str, Vec<Xmas>>;
+
+fn accepted_combinations(insts: &Instructions) -> u64 {
+    let mut cache: Cache = Cache::default();
+    cache_routes_to(insts, "A", &mut cache);
+    cache
+        .get("A")
+        .unwrap()
+        .iter()
+        .map(|result| {
+            result
+                .iter()
+                .map(|range| (1 + range.end() - range.start()) as u64)
+                .product::<u64>()
+        })
+        .sum::<u64>()
+}
+
+fn filter(result: &mut Xmas, test: &Test) {
+    match test {
+        Test::Greater(index, val) => {
+            result[*index] = *result[*index].start().max(&(val + 1))..=*result[*index].end()
+        }
+        Test::Less(index, val) => {
+            result[*index] = *result[*index].start()..=*result[*index].end().min(&(val - 1))
+        }
+        Test::Always => {}
+    }
+}
+
+fn cache_routes_to<'name>(
+    insts: &Instructions<'name, '_>,
+    to: &'name str,
+    cache: &mut Cache<'name>,
+) {
+    if !cache.contains_key(to) {
+        let mut results = vec![];
+        if to == "in" {
+            results.push([0, 1, 2, 3].map(|_| 1..=4000));
+        } else {
+            for (&name, commands) in insts.iter() {
+                for (index, (test, goto)) in commands.iter().enumerate() {
+                    if goto == &to {
+                        cache_routes_to(insts, name, cache);
+                        for sub_route in cache.get(name).unwrap() {
+                            let mut sub_route = sub_route.clone();
+                            for (prev_test, _) in commands.iter().take(index) {
+                                filter(&mut sub_route, &prev_test.reverse());
+                            }
+                            filter(&mut sub_route, test);
+                            results.push(sub_route);
+                        }
+                    }
+                }
+            }
+        }
+        cache.insert(to, results);
+    }
+}
+
+#[tracing::instrument]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "px{a<2006:qkq,m>2090:A,rfg}
+        pv{a>1716:R,A}
+        lnx{m>1548:A,A}
+        rfg{s<537:gd,x>2440:R,A}
+        qs{s>3448:A,lnx}
+        qkq{x<1416:A,crn}
+        crn{x>2662:A,R}
+        in{s<1351:px,qqz}
+        qqz{s>2770:qs,m<1801:hdj,R}
+        gd{a>3333:R,R}
+        hdj{m>838:A,pv}
+
+        {x=787,m=2655,a=1222,s=2876}
+        {x=1679,m=44,a=2067,s=496}
+        {x=2036,m=264,a=79,s=2244}
+        {x=2461,m=1339,a=466,s=291}
+        {x=2127,m=1623,a=2188,s=1013}";
+    Ok(vec![Example::new("example", input)
+        .part_a("19114")
+        .part_b("167409079868000")])
+}
+
+// a tree of workflows from in, so every part ends up accepted or rejected. each
+// rule splits the ratings that can reach it, so no route is impossible
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut taken = FxHashSet::from_iter(["in".to_string()]);
+    let mut todo = vec![("in".to_string(), [(1, 4000); 4])];
+    let mut workflows = vec![];
+    while let Some((name, mut ratings)) = todo.pop() {
+        let mut target = |rng: &mut Rng, ratings| {
+            if workflows.len() + todo.len() < size && rng.bool() {
+                let len = rng.usize(2..=3);
+                let next = gen::name(rng, len, &mut taken);
+                todo.push((next.clone(), ratings));
+                next
+            } else if rng.bool() {
+                "A".into()
+            } else {
+                "R".into()
+            }
+        };
+        let mut rules = vec![];
+        for _ in 0..rng.usize(1..=3) {
+            let index = rng.usize(0..4);
+            let (low, high) = ratings[index];
+            if low == high {
+                continue;
+            }
+            let mut passed = ratings;
+            let rule = if rng.bool() {
+                let value = rng.u32(low + 1..=high);
+                (passed[index].1, ratings[index].0) = (value - 1, value);
+                format!("<{value}")
+            } else {
+                let value = rng.u32(low..high);
+                (passed[index].0, ratings[index].1) = (value + 1, value);
+                format!(">{value}")
+            };
+            rules.push(format!(
+                "{}{rule}:{}",
+                ['x', 'm', 'a', 's'][index],
+                target(rng, passed)
+            ));
+        }
+        rules.push(target(rng, ratings));
+        workflows.push(format!("{name}{{{}}}\n", rules.join(",")));
+    }
+    rng.shuffle(&mut workflows);
+    let parts = (0..size)
+        .map(|_| {
+            let [x, m, a, s] = [(); 4].map(|_| rng.u32(1..=4000));
+            format!("{{x={x},m={m},a={a},s={s}}}\n")
+        })
+        .collect::<String>();
+    format!("{}\n{parts}", workflows.concat())
+}
@@ -0,0 +1,98 @@
+use anyhow::Error;
+use fastrand::Rng;
+use itertools::Itertools;
+
+use crate::{fixtures::Example, input::{non_empty_lines, ParseError}};
+
+type Game = (u32, Vec<[u32; 3]>);
+
+fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+    non_empty_lines(input)
+        .map(|line| {
+            let (game, turns) = line.split_once(line.text, ": ")?;
+            let game_index = line.parse(line.strip_prefix(game, "Game ")?)?;
+            let game_turns = turns
+                .split("; ")
+                .map(|turn| {
+                    let mut amounts = [0, 0, 0];
+                    for part in turn.split(", ") {
+                        let (part_num, part_type) = line.split_once(part, " ")?;
+                        let index = ["red", "green", "blue"]
+                            .iter()
+                            .position(|&cube_type| cube_type == part_type)
+                            .ok_or_else(|| {
+                                line.error(part_type, format!("unknown cube '{}'", part_type))
+                            })?;
+                        amounts[index] = line.parse(part_num)?;
+                    }
+                    Ok(amounts)
+                })
+                .collect::<Result<Vec<_>, ParseError>>()?;
+            tracing::debug!("{} {:?}", game_index, game_turns);
+            Ok((game_index, game_turns))
+        })
+        .collect()
+}
+
+#[tracing::instrument(skip(input), fields(day=2))]
+pub fn part_a(input: &str) -> Result<u32, Error> {
+    Ok(parse(input)?
+        .iter()
+        .filter(|(_, turns)| {
+            turns.iter().all(|turn| {
+                turn.iter()
+                    .zip([12, 13, 14])
+                    .all(|(&turn_amount, type_max)| turn_amount <= type_max)
+            })
+        })
+        .map(|(index, _)| index)
+        .sum::<u32>())
+}
+
+#[tracing::instrument(skip(input), fields(day=2))]
+pub fn part_b(input: &str) -> Result<u32, Error> {
+    Ok(parse(input)?
+        .iter()
+        .map(|(_, turns)| {
+            turns.iter().fold([0, 0, 0], |agg, turn| {
+                agg.iter()
+                    .zip(turn)
+                    .map(|(&a, &b)| u32::max(a, b))
+                    .collect_vec()
+                    .try_into()
+                    .unwrap()
+            })
+        })
+        .map(|maxes| maxes.into_iter().product::<u32>())
+        .sum::<u32>())
+}
+
+#[tracing::instrument]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+    Ok(vec![Example::new("example", input)
+        .part_a("8")
+        .part_b("2286")])
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (1..=size)
+        .map(|game| {
+            let turns = (0..rng.usize(1..=6))
+                .map(|_| {
+                    let mut colours = ["red", "green", "blue"];
+                    rng.shuffle(&mut colours);
+                    colours[..rng.usize(1..=3)]
+                        .iter()
+                        .map(|colour| format!("{} {}", rng.u32(1..=20), colour))
+                        .join(", ")
+                })
+                .join("; ");
+            format!("Game {game}: {turns}\n")
+        })
+        .collect()
+}
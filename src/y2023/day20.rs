@@ -0,0 +1,405 @@
+use std::collections::VecDeque;
+
+use anyhow::{anyhow, bail, Error};
+use fastrand::Rng;
+use itertools::Itertools;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    fixtures::Example,
+    gen,
+    input::{non_empty_lines, ParseError},
+    Part,
+};
+
+#[derive(PartialEq, Debug)]
+enum ModuleType {
+    Broadcaster,
+    FlipFlop(PulseType),
+    Conjunction(FxHashMap<usize, PulseType>),
+    SingleInputConjunction(PulseType),
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum PulseType {
+    Low,
+    High,
+}
+
+impl std::ops::Not for PulseType {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        match self {
+            PulseType::Low => PulseType::High,
+            PulseType::High => PulseType::Low,
+        }
+    }
+}
+
+#[tracing::instrument(skip(input), fields(day = 20))]
+pub fn part_a(input: &str) -> Result<usize, Error> {
+    Ok(run(input, false)?.0)
+}
+
+#[tracing::instrument(skip(input), fields(day = 20))]
+pub fn part_b(input: &str) -> Result<usize, Error> {
+    Ok(run(input, true)?.1.unwrap())
+}
+
+// each module's type, id and target ids
+type Module<'a> = (ModuleType, &'a str, Vec<&'a str>);
+
+fn parse(input: &str) -> Result<Vec<Module<'_>>, ParseError> {
+    non_empty_lines(input)
+        .map(|line| {
+            let (type_and_id, targets) = line.split_once(line.text, " -> ")?;
+            let (mod_type, id) = if type_and_id == "broadcaster" {
+                (ModuleType::Broadcaster, type_and_id)
+            } else if let Some(id) = type_and_id.strip_prefix('%') {
+                (ModuleType::FlipFlop(PulseType::Low), id)
+            } else if let Some(id) = type_and_id.strip_prefix('&') {
+                (ModuleType::Conjunction(FxHashMap::default()), id)
+            } else {
+                return Err(line.error(type_and_id, "expected 'broadcaster', '%' or '&'"));
+            };
+            Ok((mod_type, id, targets.split(", ").collect_vec()))
+        })
+        .collect()
+}
+
+// returns low * high pulses after 1000 presses, and if has_rx the presses needed to reach rx
+fn run(input: &str, has_rx: bool) -> Result<(usize, Option<usize>), Error> {
+    // the counters feeding rx are 12 bits, and a press can set off pulses forever
+    const MAX_PRESSES: usize = 1 << 16;
+    const MAX_PULSES: usize = 1 << 20;
+    let modules = parse(input)?;
+
+    let mut id_to_index: FxHashMap<&str, usize> = modules
+        .iter()
+        .enumerate()
+        .map(|(index, (_, id, _))| (*id, index))
+        .collect();
+    if has_rx {
+        id_to_index.insert("rx", usize::MAX);
+    }
+
+    let mut modules = modules
+        .into_iter()
+        .map(|(mod_type, _, targets)| {
+            (
+                mod_type,
+                targets
+                    .into_iter()
+                    .map(|target| id_to_index.get(&target).copied())
+                    .collect_vec(),
+            )
+        })
+        .collect_vec();
+
+    let mut all_sources = vec![vec![]; modules.len()];
+    modules
+        .iter()
+        .enumerate()
+        .for_each(|(index, (_, targets))| {
+            targets.iter().for_each(|target| {
+                if let Some(target) = target {
+                    if *target != usize::MAX {
+                        all_sources[*target].push(index);
+                    }
+                }
+            });
+        });
+
+    modules
+        .iter_mut()
+        .enumerate()
+        .filter(|(_, (mod_type, _))| matches!(mod_type, ModuleType::Conjunction(_)))
+        .for_each(|(index, (mod_type, _))| {
+            *mod_type = match mod_type {
+                ModuleType::Conjunction(_) => {
+                    let sources = &all_sources[index];
+                    if sources.len() == 1 {
+                        ModuleType::SingleInputConjunction(PulseType::Low)
+                    } else {
+                        ModuleType::Conjunction(
+                            all_sources[index]
+                                .iter()
+                                .map(|&source| (source, PulseType::Low))
+                                .collect(),
+                        )
+                    }
+                }
+                _ => panic!(),
+            };
+        });
+
+    tracing::debug!("modules indexed {:?}", modules);
+
+    let mut rx_watches = if has_rx {
+        // rx is pointed at by one Conjunction
+        let targets_rx = modules
+            .iter()
+            .enumerate()
+            .find(|(_, (_, targets))| targets.contains(&Some(usize::MAX)))
+            .map(|(index, _)| index)
+            .ok_or_else(|| anyhow!("nothing sends to rx"))?;
+        if !matches!(modules[targets_rx].0, ModuleType::Conjunction(_)) {
+            bail!("expected rx to be sent to by a conjunction");
+        }
+
+        let sources = modules
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, targets))| targets.contains(&Some(targets_rx)))
+            .map(|(index, _)| (index, None))
+            .collect_vec();
+        if sources.is_empty()
+            || !sources.iter().all(|&(source, _)| {
+                matches!(
+                    modules[source].0,
+                    ModuleType::SingleInputConjunction(_)
+                )
+            })
+        {
+            bail!("expected the conjunction before rx to be sent to by single input conjunctions");
+        }
+        sources
+    } else {
+        vec![]
+    };
+
+    let mut total_low = 0;
+    let mut total_high = 0;
+    let mut run = 1;
+
+    loop {
+        let mut next_pulses = vec![(
+            usize::MAX,
+            modules
+                .iter()
+                .enumerate()
+                .find_map(|(index, (mod_type, _))| {
+                    if *mod_type == ModuleType::Broadcaster {
+                        Some(index)
+                    } else {
+                        None
+                    }
+                }),
+            PulseType::Low,
+        )];
+
+        let mut pulses = 0;
+        while !next_pulses.is_empty() {
+            let mut last_pulses: Vec<(usize, Option<usize>, PulseType)> = vec![];
+            std::mem::swap(&mut next_pulses, &mut last_pulses);
+            pulses += last_pulses.len();
+            if pulses > MAX_PULSES {
+                bail!("press {} sent over {} pulses", run, MAX_PULSES);
+            }
+
+            if run <= 1000 {
+                let (low, high) = last_pulses
+                    .iter()
+                    .fold((0, 0), |(low, high), (_, _, pulse)| {
+                        (low + !*pulse as usize, high + *pulse as usize)
+                    });
+                total_low += low;
+                total_high += high;
+            }
+
+            for (from_index, to_index, pulse) in last_pulses {
+                if let Some(to_index) = to_index {
+                    if let Some((mod_type, targets)) = modules.get_mut(to_index) {
+                        let maybe_send = match mod_type {
+                            ModuleType::Broadcaster => Some(pulse),
+                            ModuleType::FlipFlop(val) => {
+                                if pulse == PulseType::Low {
+                                    *val = !*val;
+                                    Some(*val)
+                                } else {
+                                    None
+                                }
+                            }
+                            ModuleType::Conjunction(sources) => {
+                                sources.insert(from_index, pulse);
+                                if sources.iter().all(|(_, stored)| *stored == PulseType::High) {
+                                    Some(PulseType::Low)
+                                } else {
+                                    Some(PulseType::High)
+                                }
+                            }
+                            ModuleType::SingleInputConjunction(val) => {
+                                if pulse == PulseType::Low {
+                                    if let Some((_, wrap)) = rx_watches
+                                        .iter_mut()
+                                        .filter(|(_, wrap)| wrap.is_none())
+                                        .find(|(index, _)| *index == to_index)
+                                    {
+                                        *wrap = Some(run);
+                                    }
+                                }
+                                *val = pulse;
+                                Some(!*val)
+                            }
+                        };
+
+                        if let Some(send) = maybe_send {
+                            next_pulses
+                                .extend(targets.iter().map(|&target| (to_index, target, send)));
+                        }
+                    }
+                }
+            }
+        }
+
+        if run >= 1000 && (!has_rx || rx_watches.iter().all(|(_, wrap)| wrap.is_some())) {
+            break;
+        }
+        if run >= MAX_PRESSES {
+            bail!("rx's counters didn't all cycle in {} presses", MAX_PRESSES);
+        }
+        run += 1;
+    }
+
+    let part_a = total_low * total_high;
+    if has_rx {
+        Ok((
+            part_a,
+            Some(crate::utils::lcm(&rx_watches
+                .into_iter()
+                .map(|(_, wrap)| wrap.unwrap())
+                .collect_vec())),
+        ))
+    } else {
+        Ok((part_a, None))
+    }
+}
+
+// presses the button until rx gets a low pulse, rather than finding the counters
+// that feed it. only for inputs that get there in a few million presses
+pub fn reference(part: Part, example: &Example) -> Result<usize, Error> {
+    const MAX_PRESSES: usize = 1 << 22;
+    let modules = parse(&example.input)?;
+    let kinds = modules
+        .iter()
+        .map(|(mod_type, id, targets)| (*id, (mod_type, targets)))
+        .collect::<FxHashMap<_, _>>();
+    let mut flip_flops = FxHashMap::default();
+    let mut conjunctions = modules
+        .iter()
+        .filter(|(mod_type, _, _)| matches!(mod_type, ModuleType::Conjunction(_)))
+        .map(|(_, id, _)| (*id, FxHashMap::default()))
+        .collect::<FxHashMap<_, _>>();
+    for (_, id, to) in modules.iter() {
+        for target in to {
+            if let Some(inputs) = conjunctions.get_mut(target) {
+                inputs.insert(*id, false);
+            }
+        }
+    }
+    let (mut low, mut high) = (0, 0);
+    for press in 1..=MAX_PRESSES {
+        let mut pulses = VecDeque::from([("button", "broadcaster", false)]);
+        while let Some((from, to, pulse)) = pulses.pop_front() {
+            if press <= 1000 {
+                (low, high) = if pulse { (low, high + 1) } else { (low + 1, high) };
+            }
+            if (part, to, pulse) == (Part::B, "rx", false) {
+                return Ok(press);
+            }
+            let Some(&(mod_type, targets)) = kinds.get(to) else {
+                continue;
+            };
+            let send = match mod_type {
+                ModuleType::Broadcaster => Some(pulse),
+                ModuleType::FlipFlop(_) if !pulse => {
+                    let on = flip_flops.entry(to).or_insert(false);
+                    *on = !*on;
+                    Some(*on)
+                }
+                ModuleType::Conjunction(_) => {
+                    let inputs = conjunctions.get_mut(to).unwrap();
+                    inputs.insert(from, pulse);
+                    Some(!inputs.values().all(|&pulse| pulse))
+                }
+                _ => None,
+            };
+            if let Some(send) = send {
+                pulses.extend(targets.iter().map(|target| (to, *target, send)));
+            }
+        }
+        if part == Part::A && press == 1000 {
+            return Ok(low * high);
+        }
+    }
+    bail!("rx got no low pulse in {} presses", MAX_PRESSES)
+}
+
+#[tracing::instrument]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    // there's no rx in the examples, so nothing for part b
+    Ok(vec![
+        Example::new(
+            "simple",
+            "broadcaster -> a, b, c
+        %a -> b
+        %b -> c
+        %c -> inv
+        &inv -> a",
+        )
+        .part_a("32000000"),
+        Example::new(
+            "interesting",
+            "broadcaster -> a
+        %a -> inv, con
+        &inv -> b
+        %b -> con
+        &con -> output",
+        )
+        .part_a("11687500"),
+    ])
+}
+
+// counters as the real inputs have them. each is a chain of flip-flops counting up
+// in binary, with a conjunction that fires once the count reaches a number and
+// resets it, so rx gets a low pulse after as many presses as the numbers' lcm
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    // small sizes get short counters, so the reference can press its way to rx
+    let bits = (size / 2).clamp(2, 12);
+    let mut taken = FxHashSet::from_iter(["rx".to_string()]);
+    let last = gen::name(rng, 2, &mut taken);
+    let mut modules = vec![];
+    let mut firsts = vec![];
+    for _ in 0..size.clamp(2, 4) {
+        // odd and with the top bit set, so the lowest and highest flip-flops send
+        // to the conjunction
+        let count = rng.u32(1 << (bits - 1)..1 << bits) | 1;
+        let flip_flops = (0..bits)
+            .map(|_| gen::name(rng, 2, &mut taken))
+            .collect_vec();
+        let conjunction = gen::name(rng, 2, &mut taken);
+        let inverter = gen::name(rng, 2, &mut taken);
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut targets = flip_flops.get(bit + 1).into_iter().collect_vec();
+            if count & 1 << bit != 0 {
+                targets.push(&conjunction);
+            }
+            modules.push(format!("%{flip_flop} -> {}", targets.iter().join(", ")));
+        }
+        let resets = flip_flops
+            .iter()
+            .enumerate()
+            .filter(|&(bit, _)| bit == 0 || count & 1 << bit == 0)
+            .map(|(_, flip_flop)| flip_flop);
+        modules.push(format!(
+            "&{conjunction} -> {}",
+            resets.chain([&inverter]).join(", ")
+        ));
+        modules.push(format!("&{inverter} -> {last}"));
+        firsts.push(flip_flops[0].clone());
+    }
+    modules.push(format!("&{last} -> rx"));
+    modules.push(format!("broadcaster -> {}", firsts.join(", ")));
+    rng.shuffle(&mut modules);
+    modules.join("\n") + "\n"
+}
@@ -1,17 +1,25 @@
 use std::{collections::VecDeque, hash::Hasher};
 
+use anyhow::{anyhow, bail, Error};
+use fastrand::Rng;
 use glam::{ivec2, IVec2};
 use itertools::Itertools;
 use rustc_hash::{FxHashMap, FxHasher};
 
+use crate::{fixtures::Example, input::grid, Answer, Part};
+
+const STEPS: [usize; 2] = [64, 26501365];
+
+#[tracing::instrument(skip(input), fields(day = 21))]
+pub fn part_a(input: &str) -> Result<usize, Error> {
+    let (map_chars, start_pos) = parse(input)?;
+    Ok(Map::new(&map_chars, start_pos, 1).part_a(STEPS[0]))
+}
+
 #[tracing::instrument(skip(input), fields(day = 21))]
-pub fn solve(input: &str) -> String {
-    let (map_chars, start_pos) = parse(input);
-    format!(
-        "{}/{}",
-        Map::new(&map_chars, start_pos, 1).part_a(64),
-        Map::new(&map_chars, start_pos, 2).part_b(26501365)
-    )
+pub fn part_b(input: &str) -> Result<usize, Error> {
+    let (map_chars, start_pos) = parse(input)?;
+    Map::new(&map_chars, start_pos, 2).part_b(STEPS[1])
 }
 
 struct Map {
@@ -20,11 +28,15 @@ struct Map {
     start_pos: IVec2,
 }
 
-fn parse(input: &str) -> (Vec<Vec<char>>, IVec2) {
-    let map_chars = input
-        .lines()
-        .map(|line| line.trim().chars().collect_vec())
-        .collect_vec();
+fn parse(input: &str) -> Result<(Vec<Vec<char>>, IVec2), Error> {
+    let map_chars = grid(input, |ch| ".#S".contains(ch).then_some(ch))?;
+    if map_chars.len() != map_chars[0].len() {
+        bail!(
+            "expected a square map, not {} by {}",
+            map_chars[0].len(),
+            map_chars.len()
+        );
+    }
     let start_pos = map_chars
         .iter()
         .enumerate()
@@ -37,8 +49,8 @@ fn parse(input: &str) -> (Vec<Vec<char>>, IVec2) {
                 }
             })
         })
-        .unwrap();
-    (map_chars, start_pos)
+        .ok_or_else(|| anyhow!("no 'S' in the map"))?;
+    Ok((map_chars, start_pos))
 }
 
 type PageRef = (u64, usize, IVec2);
@@ -46,11 +58,10 @@ type Cache = FxHashMap<u64, Page>;
 type CacheItem = <Cache as IntoIterator>::Item;
 
 impl Map {
-    fn new(map_chars: &Vec<Vec<char>>, start_pos: IVec2, mult: i32) -> Self {
+    fn new(map_chars: &[Vec<char>], start_pos: IVec2, mult: i32) -> Self {
         let mut size = ivec2(map_chars[0].len() as i32, map_chars.len() as i32);
         let original_size = size;
         size *= mult;
-        assert!(size.x == size.y);
         let grid = (0..size.y)
             .flat_map(|y| {
                 (0..size.x)
@@ -75,16 +86,52 @@ impl Map {
     fn get(&self, pos: IVec2) -> bool {
         self.inside(pos) && self.grid[(pos.y * self.size + pos.x) as usize]
     }
+    // the fewest steps to each plot from the starts, stepping out a wave at a time
+    fn fill(&self, starts: &[StepAndPos]) -> Vec<Option<usize>> {
+        let index = |pos: IVec2| (pos.y * self.size + pos.x) as usize;
+        let mut min_steps_to_pos = vec![None; (self.size * self.size) as usize];
+        let mut next_wave = vec![];
+        for &(offset, start) in starts.iter() {
+            min_steps_to_pos[index(start)] = Some(offset);
+            next_wave.push(start);
+        }
+
+        while !next_wave.is_empty() {
+            let mut prev_wave = vec![];
+            std::mem::swap(&mut next_wave, &mut prev_wave);
+
+            for prev_pos in prev_wave {
+                let step = min_steps_to_pos[index(prev_pos)].unwrap() + 1;
+                for &dir in DIRS.iter() {
+                    let pos = prev_pos + dir;
+                    if self.get(pos)
+                        && match min_steps_to_pos[index(pos)] {
+                            None => true,
+                            Some(s) => s > step,
+                        }
+                    {
+                        min_steps_to_pos[index(pos)] = Some(step);
+                        next_wave.push(pos);
+                    }
+                }
+            }
+        }
+        min_steps_to_pos
+    }
+
     fn part_a(&self, steps: usize) -> usize {
-        let page = Page::new(self, &[(0, self.start_pos)], 0, steps & 1);
-        page.score(steps, steps & 1)
+        self.fill(&[(0, self.start_pos)])
+            .into_iter()
+            .flatten()
+            .filter(|&min_steps| min_steps <= steps && min_steps % 2 == steps % 2)
+            .count()
     }
 
-    fn part_b(&self, steps: usize) -> usize {
+    fn part_b(&self, steps: usize) -> Result<usize, Error> {
         let mut active_page_refs: VecDeque<PageRef> = [(0, steps, IVec2::ZERO)]
             .into_iter()
             .collect::<VecDeque<_>>();
-        let mut cached_pages: Cache = [(0, Page::new(self, &[(0, self.start_pos)], 0, steps & 1))]
+        let mut cached_pages: Cache = [(0, Page::new(self, &[(0, self.start_pos)], 0, steps & 1)?)]
             .into_iter()
             .collect::<FxHashMap<_, _>>();
         let mut page: Option<&Page> = None;
@@ -100,7 +147,7 @@ impl Map {
 
             let mut new_pages: Vec<CacheItem> = vec![];
 
-            let mut expand = |pos: IVec2, dir| {
+            let mut expand = |pos: IVec2, dir| -> Result<(), Error> {
                 let pos_in_dir = pos + dir;
                 let (steps_to_border, border_hash, border_starts) = page.unwrap().get_border(dir);
 
@@ -124,33 +171,34 @@ impl Map {
                         if !cached_pages.contains_key(border_hash) {
                             new_pages.push((
                                 *border_hash,
-                                Page::new(self, border_starts, *border_hash, steps & 1),
+                                Page::new(self, border_starts, *border_hash, steps & 1)?,
                             ));
                         }
                         active_page_refs.push_back((*border_hash, steps_left - steps_to_border, pos_in_dir));
                     }
                 }
+                Ok(())
             };
 
             if page_pos == IVec2::ZERO {
                 for &dir in DIRS.iter() {
-                    expand(page_pos, dir);
+                    expand(page_pos, dir)?;
                 }
             } else if page_pos.x == 0 {
-                expand(page_pos, page_pos.signum());
-                expand(page_pos, IVec2::X);
-                expand(page_pos, -IVec2::X);
+                expand(page_pos, page_pos.signum())?;
+                expand(page_pos, IVec2::X)?;
+                expand(page_pos, -IVec2::X)?;
             } else {
-                expand(page_pos, ivec2(page_pos.x.signum(), 0));
+                expand(page_pos, ivec2(page_pos.x.signum(), 0))?;
             }
 
             if !new_pages.is_empty() {
                 page = None;
-                cached_pages.extend(new_pages.into_iter());
+                cached_pages.extend(new_pages);
             }
         }
 
-        total_score
+        Ok(total_score)
     }
 }
 
@@ -186,12 +234,10 @@ impl Page {
                 .count()
         }
     }
-    fn new(map: &Map, starts: &[StepAndPos], hash: u64, odd: usize) -> Self {
-        let mut min_steps_to_pos = vec![None; (map.size * map.size) as usize];
+    // pages join up through their edges, so every plot on them has to be reachable
+    fn new(map: &Map, starts: &[StepAndPos], hash: u64, odd: usize) -> Result<Self, Error> {
+        let min_steps_to_pos = map.fill(starts);
 
-        let set = |min_steps_to_pos: &mut Vec<Option<usize>>, pos: IVec2, step: usize| {
-            min_steps_to_pos[(pos.y * map.size + pos.x) as usize] = Some(step);
-        };
         let get = |min_steps_to_pos: &Vec<Option<usize>>, pos: IVec2| {
             min_steps_to_pos[(pos.y * map.size + pos.x) as usize]
         };
@@ -208,33 +254,6 @@ impl Page {
             get(min_steps_to_pos, get_indexed_coord(index, dir))
         };
 
-        let mut next_wave = vec![];
-        for &(offset, start) in starts.iter() {
-            set(&mut min_steps_to_pos, start, offset);
-            next_wave.push(start);
-        }
-
-        while !next_wave.is_empty() {
-            let mut prev_wave = vec![];
-            std::mem::swap(&mut next_wave, &mut prev_wave);
-
-            for prev_pos in prev_wave {
-                let step = get(&min_steps_to_pos, prev_pos).unwrap() + 1;
-                for &dir in DIRS.iter() {
-                    let pos = prev_pos + dir;
-                    if map.get(pos)
-                        && match get(&min_steps_to_pos, pos) {
-                            None => true,
-                            Some(s) => s > step,
-                        }
-                    {
-                        set(&mut min_steps_to_pos, pos, step);
-                        next_wave.push(pos);
-                    }
-                }
-            }
-        }
-
         let min_steps_to_fill = min_steps_to_pos
             .iter()
             .filter_map(|steps| *steps)
@@ -249,6 +268,12 @@ impl Page {
             })
             .count();
 
+        if DIRS.iter().any(|&dir| {
+            (0..map.size).any(|index| get_indexed(&min_steps_to_pos, index, dir).is_none())
+        }) {
+            bail!("expected every plot on the map's edges to be reachable");
+        }
+
         let borders = DIRS.map(|dir| {
             
             let steps_to_border = (0..map.size).fold(usize::MAX, |min, index| {
@@ -277,20 +302,19 @@ impl Page {
             (steps_to_border, border_hash, border)
         });
 
-        Page {
+        Ok(Page {
             min_steps_to_pos,
             min_steps_to_fill,
             score_when_full,
             borders,
             hash,
-        }
+        })
     }
 }
 
 #[tracing::instrument]
-pub fn test() -> (String, String) {
-    let (map_chars, start_pos) = parse(
-        "...........
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "...........
     .....###.#.
     .###.##..#.
     ..#.#...#..
@@ -300,15 +324,92 @@ pub fn test() -> (String, String) {
     .......##..
     .##.#.####.
     .##..##.##.
-    ...........",
-    );
-
-    (
-        format!(
-            "{}/{}",
-            Map::new(&map_chars, start_pos, 1).part_a(6),
-            Map::new(&map_chars, start_pos, 2).part_b(5000)
-        ),
-        "16/16733044".into(),
-    )
+    ...........";
+    Ok(vec![
+        Example::new("6 steps", input)
+            .with_param("steps", 6)
+            .part_a("16"),
+        Example::new("1000 steps", input)
+            .with_param("steps", 1000)
+            .part_b("668697"),
+        Example::new("5000 steps", input)
+            .with_param("steps", 5000)
+            .part_b("16733044"),
+    ])
+}
+
+pub fn solve_example(part: Part, example: &Example) -> Result<Answer, Error> {
+    let (map_chars, start_pos) = parse(&example.input)?;
+    let steps = example.param_or("steps", STEPS[part.index()] as i64) as usize;
+    Ok(match part {
+        Part::A => Map::new(&map_chars, start_pos, 1).part_a(steps),
+        Part::B => Map::new(&map_chars, start_pos, 2).part_b(steps)?,
+    }
+    .into())
+}
+
+// steps out one at a time, over the map repeating forever for part b, counting
+// the plots reached on the last step's parity. only for a few thousand steps
+pub fn reference(part: Part, example: &Example) -> Result<usize, Error> {
+    const MAX_STEPS: usize = 5000;
+    let (map_chars, start_pos) = parse(&example.input)?;
+    let steps = example.param_or("steps", STEPS[part.index()] as i64) as usize;
+    if steps > MAX_STEPS {
+        bail!("too many steps for the reference ({}), at most {}", steps, MAX_STEPS);
+    }
+    let size = map_chars.len() as i32;
+    let rock = |pos: IVec2| {
+        map_chars[pos.y.rem_euclid(size) as usize][pos.x.rem_euclid(size) as usize] == '#'
+    };
+    // as bits, covering everywhere steps can get to
+    let reach = 2 * steps as i32 + 1;
+    let mut visited = vec![0u64; (reach * reach) as usize / 64 + 1];
+    let mut visit = |pos: IVec2| {
+        let offset = pos - start_pos + IVec2::splat(steps as i32);
+        let index = (offset.y * reach + offset.x) as usize;
+        let new = visited[index / 64] & 1 << (index % 64) == 0;
+        visited[index / 64] |= 1 << (index % 64);
+        new
+    };
+    visit(start_pos);
+    let mut wave = vec![start_pos];
+    let mut reached = [1, 0];
+    for step in 1..=steps {
+        wave = wave
+            .into_iter()
+            .flat_map(|pos| [IVec2::X, IVec2::Y, IVec2::NEG_X, IVec2::NEG_Y].map(|dir| pos + dir))
+            .filter(|&pos| {
+                let inside = pos.x >= 0 && pos.y >= 0 && pos.x < size && pos.y < size;
+                (part == Part::B || inside) && !rock(pos) && visit(pos)
+            })
+            .collect();
+        reached[step % 2] += wave.len();
+    }
+    Ok(reached[steps % 2])
+}
+
+// an odd sized square with S in the middle, and the middle row and column and the
+// edges clear of rocks as the real inputs have them, which part b relies on
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5) | 1;
+    let middle = size / 2;
+    (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| {
+                    if (x, y) == (middle, middle) {
+                        'S'
+                    } else if [0, middle, size - 1].contains(&x)
+                        || [0, middle, size - 1].contains(&y)
+                        || rng.usize(0..8) != 0
+                    {
+                        '.'
+                    } else {
+                        '#'
+                    }
+                })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
 }
@@ -1,7 +1,11 @@
+use anyhow::Error;
+use fastrand::Rng;
 use glam::{ivec3, IVec3};
 use itertools::Itertools;
 use rustc_hash::{FxHashMap, FxHashSet};
 
+use crate::{fixtures::Example, input::{non_empty_lines, ParseError}};
+
 struct Shape {
     pos: IVec3,
     size: IVec3,
@@ -43,27 +47,29 @@ impl Shape {
     }
 }
 
-#[tracing::instrument(skip(input), fields(day = 22))]
-pub fn solve(input: &str) -> String {
-    let mut shapes = input
-        .lines()
+type Supports = Vec<FxHashSet<usize>>;
+
+fn parse(input: &str) -> Result<Vec<Shape>, ParseError> {
+    non_empty_lines(input)
         .map(|line| {
-            let coords = line
-                .trim()
-                .split('~')
-                .map(|coord_str| {
-                    let xyz = coord_str
-                        .split(',')
-                        .map(|coord_part_str| coord_part_str.parse::<i32>().unwrap())
-                        .collect_tuple::<(i32, i32, i32)>()
-                        .unwrap();
-                    ivec3(xyz.0, xyz.1, xyz.2)
-                })
-                .collect_tuple::<(IVec3, IVec3)>()
-                .unwrap();
-            Shape::from(coords)
+            let (start, end) = line.split_once(line.text, "~")?;
+            let [start, end] = [start, end].map(|coords| -> Result<IVec3, ParseError> {
+                let (x, rest) = line.split_once(coords, ",")?;
+                let (y, z) = line.split_once(rest, ",")?;
+                Ok(ivec3(line.parse(x)?, line.parse(y)?, line.parse(z)?))
+            });
+            let (start, end) = (start?, end?);
+            if end.cmplt(start).any() {
+                return Err(line.error(line.text, "expected the end not to be before the start"));
+            }
+            Ok(Shape::from((start, end)))
         })
-        .collect_vec();
+        .collect()
+}
+
+// drop the bricks and return which bricks are above and below each one
+fn settle(input: &str) -> Result<(Supports, Supports), ParseError> {
+    let mut shapes = parse(input)?;
 
     shapes.sort_by_key(|shape| shape.pos.z);
 
@@ -117,17 +123,26 @@ pub fn solve(input: &str) -> String {
         })
         .collect_vec();
 
-    let part_a = (0..shapes.len())
+    Ok((aboves, belows))
+}
+
+#[tracing::instrument(skip(input), fields(day = 22))]
+pub fn part_a(input: &str) -> Result<usize, Error> {
+    let (aboves, belows) = settle(input)?;
+    Ok((0..aboves.len())
         .filter(|&index| {
             aboves[index].is_empty() || aboves[index].iter().all(|&above| belows[above].len() > 1)
         })
-        .count();
+        .count())
+}
 
+#[tracing::instrument(skip(input), fields(day = 22))]
+pub fn part_b(input: &str) -> Result<usize, Error> {
     fn get_fallers(
         fallers: &mut FxHashSet<usize>,
         index: usize,
-        aboves: &Vec<FxHashSet<usize>>,
-        belows: &Vec<FxHashSet<usize>>,
+        aboves: &Supports,
+        belows: &Supports,
     ) {
         fallers.insert(index);
         let above = aboves[index]
@@ -140,29 +155,51 @@ pub fn solve(input: &str) -> String {
         }
     }
 
-    let part_b = (0..shapes.len())
+    let (aboves, belows) = settle(input)?;
+    Ok((0..aboves.len())
         .map(|index| {
             let mut fallers = FxHashSet::default();
             get_fallers(&mut fallers, index, &aboves, &belows);
             fallers.len() - 1
         })
-        .sum::<usize>();
-
-    format!("{}/{}", part_a, part_b)
+        .sum::<usize>())
 }
 
 #[tracing::instrument]
-pub fn test() -> (String, String) {
-    (
-        solve(
-            "1,0,1~1,2,1
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "1,0,1~1,2,1
         0,0,2~2,0,2
         0,2,3~2,2,3
         0,0,4~0,2,4
         2,0,5~2,2,5
         0,1,6~2,1,6
-        1,1,8~1,1,9",
-        ),
-        "5/7".into(),
-    )
+        1,1,8~1,1,9";
+    Ok(vec![Example::new("example", input)
+        .part_a("5")
+        .part_b("7")])
+}
+
+// bricks on a 10 by 10 floor, each starting above the ones before
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut z = 0;
+    let mut bricks = (0..size)
+        .map(|_| {
+            z += rng.i32(1..=3);
+            let start = ivec3(rng.i32(0..10), rng.i32(0..10), z);
+            let mut end = start;
+            let len = rng.i32(0..4);
+            match rng.usize(0..3) {
+                0 => end.x = (start.x + len).min(9),
+                1 => end.y = (start.y + len).min(9),
+                _ => end.z += len,
+            }
+            z = end.z;
+            format!(
+                "{},{},{}~{},{},{}\n",
+                start.x, start.y, start.z, end.x, end.y, end.z
+            )
+        })
+        .collect_vec();
+    rng.shuffle(&mut bricks);
+    bricks.concat()
 }
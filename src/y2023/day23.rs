@@ -1,9 +1,13 @@
 use std::collections::VecDeque;
 
+use anyhow::{anyhow, bail, Error};
+use fastrand::Rng;
 use glam::{ivec2, IVec2};
 use itertools::Itertools;
 use rustc_hash::FxHashMap;
 
+use crate::{fixtures::Example, input::{grid, ParseError}};
+
 const DIRS: [IVec2; 4] = [ivec2(1, 0), ivec2(0, 1), ivec2(-1, 0), ivec2(0, -1)];
 
 type NodeEntry = (IVec2, usize);
@@ -21,9 +25,16 @@ struct Map {
 }
 
 impl Map {
-    fn new(value: &Vec<Vec<char>>, ignore_arrows: bool) -> Self {
+    fn new(value: &[Vec<char>], ignore_arrows: bool) -> Result<Self, Error> {
         let size = ivec2(value[0].len() as i32, value.len() as i32);
-        let get_grid_ch = |pos: IVec2| value[pos.y as usize][pos.x as usize];
+        // outside the map is forest
+        let get_grid_ch = |pos: IVec2| {
+            value
+                .get(pos.y as usize)
+                .and_then(|row| row.get(pos.x as usize))
+                .copied()
+                .unwrap_or('#')
+        };
         let get_valid_dirs = |pos: IVec2, size: IVec2| -> u8 {
             let ch = get_grid_ch(pos);
             if ch == '#' {
@@ -58,36 +69,40 @@ impl Map {
             })
             .collect_vec();
 
-        Map {
+        Ok(Map {
             size,
             start: ivec2(
                 grid.iter()
                     .take(size.x as usize)
                     .position(|n| *n != 0)
-                    .unwrap() as i32,
+                    .ok_or_else(|| anyhow!("no path on the top row"))? as i32,
                 0,
             ),
             end: ivec2(
                 grid.iter()
                     .skip(((size.y - 1) * size.x) as usize)
                     .position(|n| *n != 0)
-                    .unwrap() as i32,
+                    .ok_or_else(|| anyhow!("no path on the bottom row"))? as i32,
                 size.y - 1,
             ),
             grid,
-        }
+        })
     }
 }
 
 impl Map {
     fn get(&self, pos: IVec2) -> u8 {
-        self.grid[(pos.y * self.size.x + pos.x) as usize]
+        if pos.x >= 0 && pos.y >= 0 && pos.x < self.size.x && pos.y < self.size.y {
+            self.grid[(pos.y * self.size.x + pos.x) as usize]
+        } else {
+            0
+        }
     }
     fn can_move(&self, pos: IVec2, dir_index: u8) -> bool {
         (self.get(pos) & (1 << dir_index)) != 0
     }
 
-    fn longest_route(&self) -> usize {
+    fn longest_route(&self) -> Result<usize, Error> {
         // get pos -> [maybe (to_pos, len)]
         let mut nodes = FxHashMap::<IVec2, Node>::default();
         let mut new_positions: Vec<IVec2> = vec![self.start];
@@ -120,12 +135,13 @@ impl Map {
                                 {
                                     break;
                                 }
-                                current_index = (0..4)
-                                    .find(|&test_index| {
-                                        inverse_dir(test_index) != current_index
-                                            && self.can_move(pos, test_index)
-                                    })
-                                    .unwrap();
+                                let Some(next_index) = (0..4).find(|&test_index| {
+                                    inverse_dir(test_index) != current_index
+                                        && self.can_move(pos, test_index)
+                                }) else {
+                                    break;
+                                };
+                                current_index = next_index;
                             }
                             ret
                         }
@@ -137,7 +153,10 @@ impl Map {
                 });
             }
         }
-        assert!(nodes.len() < 64);
+        // visited nodes are kept as bits of a u64
+        if nodes.len() >= 64 {
+            bail!("too many junctions ({})", nodes.len());
+        }
 
         // get pos -> index in nodes map
         let pos_to_index = nodes
@@ -156,7 +175,9 @@ impl Map {
             })
             .collect_vec();
 
-        let end_index = *pos_to_index.get(&self.end).unwrap();
+        let end_index = *pos_to_index
+            .get(&self.end)
+            .ok_or_else(|| anyhow!("no route to the end"))?;
         let mut wave = VecDeque::from([(*pos_to_index.get(&self.start).unwrap(), 0usize, 0u64)]);
         let mut results = vec![];
         while !wave.is_empty() {
@@ -174,28 +195,30 @@ impl Map {
                 }
             }
         }
-        results.into_iter().max().unwrap()
+        results
+            .into_iter()
+            .max()
+            .ok_or_else(|| anyhow!("no route to the end"))
     }
 }
 
+fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    grid(input, |ch| "#.><v^".contains(ch).then_some(ch))
+}
+
+#[tracing::instrument(skip(input), fields(day = 23))]
+pub fn part_a(input: &str) -> Result<usize, Error> {
+    Map::new(&parse(input)?, false)?.longest_route()
+}
+
 #[tracing::instrument(skip(input), fields(day = 23))]
-pub fn solve(input: &str) -> String {
-    let grid_ch = input
-        .lines()
-        .map(|line| line.trim().chars().collect_vec())
-        .collect_vec();
-    format!(
-        "{}/{}",
-        Map::new(&grid_ch, false).longest_route(),
-        Map::new(&grid_ch, true).longest_route()
-    )
+pub fn part_b(input: &str) -> Result<usize, Error> {
+    Map::new(&parse(input)?, true)?.longest_route()
 }
 
 #[tracing::instrument]
-pub fn test() -> (String, String) {
-    (
-        solve(
-            "#.#####################
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "#.#####################
         #.......#########...###
         #######.#########.#.###
         ###.....#.>.>.###.#.###
@@ -217,8 +240,46 @@ pub fn test() -> (String, String) {
         #...#...#.#.>.>.#.>.###
         #.###.###.#.###.#.#v###
         #.....###...###...#...#
-        #####################.#",
-        ),
-        "94/154".into(),
-    )
+        #####################.#";
+    Ok(vec![Example::new("example", input)
+        .part_a("94")
+        .part_b("154")])
+}
+
+// a lattice of junctions with corridors between them, sloping right and down as
+// they leave each one, like the real inputs. it's kept to 6 by 6 junctions as
+// part b tries every route
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let junctions = (size / 4).clamp(2, 6);
+    // where each column (and row) of junctions is
+    let mut at = vec![1];
+    for _ in 1..junctions {
+        at.push(at.last().unwrap() + rng.usize(3..=8));
+    }
+    let width = at.last().unwrap() + 2;
+    let mut tiles = vec![vec!['#'; width]; width];
+    for (&y, &x) in at.iter().cartesian_product(at.iter()) {
+        tiles[y][x] = '.';
+    }
+    for (&from, &to) in at.iter().tuple_windows() {
+        for &across in at.iter() {
+            // the same cells run across as rows and down as columns
+            #[allow(clippy::needless_range_loop)]
+            for along in from + 1..to {
+                let (right, down) = if along == from + 1 || along == to - 1 {
+                    ('>', 'v')
+                } else {
+                    ('.', '.')
+                };
+                tiles[across][along] = right;
+                tiles[along][across] = down;
+            }
+        }
+    }
+    tiles[0][1] = '.';
+    tiles[width - 1][width - 2] = '.';
+    tiles
+        .iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
 }
@@ -0,0 +1,464 @@
+use std::ops::RangeInclusive;
+
+use anyhow::{bail, Error};
+use fastrand::Rng;
+use glam::{I64Vec2, I64Vec3};
+use itertools::Itertools;
+use rustc_hash::FxHashSet;
+
+use crate::{fixtures::Example, input::{non_empty_lines, ParseError}, Answer, Part};
+
+// where part a's paths have to cross
+const AREA: RangeInclusive<i128> = 200000000000000..=400000000000000;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct I128Vec2 {
+    x: i128,
+    y: i128,
+}
+
+const fn i128vec2(x: i128, y: i128) -> I128Vec2 {
+    I128Vec2 { x, y }
+}
+
+impl From<I64Vec2> for I128Vec2 {
+    fn from(value: I64Vec2) -> Self {
+        i128vec2(value.x as i128, value.y as i128)
+    }
+}
+
+impl std::ops::Sub<I128Vec2> for I128Vec2 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            x: self.x.sub(rhs.x),
+            y: self.y.sub(rhs.y),
+        }
+    }
+}
+
+impl I128Vec2 {
+    fn dot(&self, rhs: Self) -> i128 {
+        (self.x * rhs.x) + (self.y * rhs.y)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct I128Vec3 {
+    x: i128,
+    y: i128,
+    z: i128,
+}
+
+const fn i128vec3(x: i128, y: i128, z: i128) -> I128Vec3 {
+    I128Vec3 { x, y, z }
+}
+
+impl From<I64Vec3> for I128Vec3 {
+    fn from(value: I64Vec3) -> Self {
+        i128vec3(value.x as i128, value.y as i128, value.z as i128)
+    }
+}
+
+impl std::ops::Index<usize> for I128Vec3 {
+    type Output = i128;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("index out of bounds"),
+        }
+    }
+}
+
+impl std::ops::Sub<I128Vec3> for I128Vec3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        i128vec3(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl I128Vec3 {
+    const ZERO: Self = i128vec3(0, 0, 0);
+
+    fn truncate(&self) -> I128Vec2 {
+        i128vec2(self.x, self.y)
+    }
+
+    fn dot(&self, rhs: Self) -> i128 {
+        (self.x * rhs.x) + (self.y * rhs.y) + (self.z * rhs.z)
+    }
+
+    fn cross(&self, rhs: Self) -> Self {
+        i128vec3(
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        )
+    }
+}
+
+#[tracing::instrument(skip(input), fields(day = 24))]
+pub fn part_a(input: &str) -> Result<usize, Error> {
+    Ok(count_intersections(&get_lines(input)?, &AREA))
+}
+
+#[tracing::instrument(skip(input), fields(day = 24))]
+pub fn part_b(input: &str) -> Result<i128, Error> {
+    find_rock(&get_lines(input)?)
+}
+
+#[derive(Debug)]
+struct Line<T> {
+    pos: T,
+    vel: T,
+}
+
+impl<T: Copy> From<[T; 2]> for Line<T> {
+    fn from(value: [T; 2]) -> Self {
+        Line {
+            pos: value[0],
+            vel: value[1],
+        }
+    }
+}
+
+fn get_lines(input: &str) -> Result<Vec<Line<I128Vec3>>, ParseError> {
+    non_empty_lines(input)
+        .map(|line| {
+            let (pos, vel) = line.split_once(line.text, "@")?;
+            let [pos, vel] = [pos, vel].map(|coords| -> Result<I128Vec3, ParseError> {
+                let (x, rest) = line.split_once(coords, ",")?;
+                let (y, z) = line.split_once(rest, ",")?;
+                Ok(i128vec3(line.parse(x)?, line.parse(y)?, line.parse(z)?))
+            });
+            Ok([pos?, vel?].into())
+        })
+        .collect()
+}
+
+fn flatten(lines: &[Line<I128Vec3>]) -> Vec<Line<I128Vec2>> {
+    lines
+        .iter()
+        .map(|line| Line {
+            pos: line.pos.truncate(),
+            vel: line.vel.truncate(),
+        })
+        .collect_vec()
+}
+
+fn intersection_2d(l1: &Line<I128Vec2>, l2: &Line<I128Vec2>, range: &RangeInclusive<i128>) -> bool {
+    let div = l1.vel.x * l2.vel.y - l1.vel.y * l2.vel.x;
+    if div == 0 {
+        false
+    } else {
+        let t1 = l2.pos.x * (l2.pos.y + l2.vel.y) - l2.pos.y * (l2.pos.x + l2.vel.x);
+        let t2 = l1.pos.x * (l1.pos.y + l1.vel.y) - l1.pos.y * (l1.pos.x + l1.vel.x);
+        let p = i128vec2(
+            (l1.vel.x * t1 - l2.vel.x * t2) / div,
+            (l1.vel.y * t1 - l2.vel.y * t2) / div,
+        );
+        if (p - l1.pos).dot(l1.vel) <= 0 || (p - l2.pos).dot(l2.vel) <= 0 {
+            return false;
+        }
+        range.contains(&p.x) && range.contains(&p.y)
+    }
+}
+
+fn count_intersections(lines: &[Line<I128Vec3>], range: &RangeInclusive<i128>) -> usize {
+    let lines = flatten(lines);
+    let mut pairs = 0;
+    for i1 in 0..lines.len() {
+        for i2 in (i1 + 1)..lines.len() {
+            if intersection_2d(&lines[i1], &lines[i2], range) {
+                pairs += 1;
+            }
+        }
+    }
+    pairs
+}
+
+fn find_rock(lines: &[Line<I128Vec3>]) -> Result<i128, Error> {
+    if lines.len() < 5 {
+        bail!("need at least 5 hailstones, not {}", lines.len());
+    }
+
+    /*
+       Got a lot of this from: https://www.reddit.com/r/adventofcode/comments/18q40he/2023_day_24_part_2_a_straightforward_nonsolver/
+       
+       Our collision path X/Y/Z DX/DY/Z
+       will hit all hails x/y/z dx/dy/dz
+       at time t...
+
+       therefore for each axis (x for example)
+
+       X + t DX = x + t dx
+       t = (X - x) / (dx - DX)
+
+       taking x and y axis we can therefore say
+
+       (X - x) / (dx - DX) = (Y - y) / (dy - DY)
+
+       can re-arrange (to remove divides) as
+
+       (X - x)(dy - DY) = (Y - y)(dx - DX)
+
+       and expand out from there to
+
+       Y DX - X DY = x dy - y dx + Y dx + y DX - x DY - X dy
+
+       because left side is same for any hail we can now swap other x'/y'/dx'/dy' into it
+
+       (dy'-dy) X + (dx-dx') Y + (y-y') DX + (x'-x) DY = x' dy' - y' dx' - x dy + y dx
+
+       or
+
+       -(dy-dy') X + (dx-dx') Y + (y-y') DX + -(x-x') DY = (y dx - x dy) - (y' dx' - x' dy')
+    */
+
+    // Based on a lua solution https://github.com/cideM/aoc2023/blob/main/d24/main.lua
+    // But modified to swap row down if zero in the pivot position
+    // which is required for test data but not real data...
+    fn guassian_elimination(mut matrix: Vec<Vec<f64>>) -> Vec<f64> {
+
+        let rows = matrix.len();
+        let cols = matrix[0].len() - 1;
+
+        for k in 0..rows {
+            for i in (k + 1)..rows {
+                if matrix[k][k] == 0.0 {
+                    matrix.swap(k, k + 1);
+                }
+                let factor = matrix[i][k] / matrix[k][k];
+                #[allow(clippy::needless_range_loop)]
+                for j in k..(cols + 1) {
+                    matrix[i][j] -= factor * matrix[k][j];
+                }
+            }
+        }
+
+        let mut solution = vec![0.0; rows];
+        for i in (0..rows).rev() {
+            let mut sum = 0.0;
+            #[allow(clippy::needless_range_loop)]
+            for j in (i + 1)..cols {
+                sum += matrix[i][j] * solution[j]
+            }
+            solution[i] = (matrix[i][cols] - sum) / matrix[i][i];
+        }
+
+        solution
+    }
+
+    fn get_matrix(lines: &[Line<I128Vec3>], x_axis: usize, y_axis: usize) -> Vec<Vec<f64>> {
+        let parts = lines
+            .iter()
+            .take(5)
+            .map(|line| {
+                [
+                    -line.vel[y_axis],
+                    line.vel[x_axis],
+                    line.pos[y_axis],
+                    -line.pos[x_axis],
+                    line.pos[y_axis] * line.vel[x_axis] - line.pos[x_axis] * line.vel[y_axis],
+                ]
+            })
+            .collect_vec();
+
+        parts
+            .iter()
+            .take(4)
+            .map(|part| {
+                part.iter()
+                    .zip(parts[4].iter())
+                    .map(|(a, b)| (a - b) as f64)
+                    .collect_vec()
+            })
+            .collect_vec()
+    }
+
+    let (_, _, dx, dy) = guassian_elimination(get_matrix(lines, 0, 1))
+        .into_iter()
+        .collect_tuple()
+        .unwrap();
+    let (_, _, dz, _) = guassian_elimination(get_matrix(lines, 2, 1))
+        .into_iter()
+        .collect_tuple()
+        .unwrap();
+    // the velocity is small enough to come out right once rounded, but the position
+    // can be a few out, so it's worked out again from the velocity without floats
+    let vel = i128vec3(dx.round() as i128, dy.round() as i128, dz.round() as i128);
+    tracing::debug!("velocity from elimination {}/{}/{}", vel.x, vel.y, vel.z);
+    match crossing(lines, vel) {
+        Crossing::At(pos) => Ok(pos.x + pos.y + pos.z),
+        _ => bail!("no rock moving at {}/{}/{} hits every hailstone", vel.x, vel.y, vel.z),
+    }
+}
+
+// where the paths cross, seen from a rock moving at some velocity
+enum Crossing {
+    Nowhere,
+    // every path is parallel to the first, so they don't pin a point down
+    Unknown,
+    At(I128Vec3),
+}
+
+// the whole-numbered point every hailstone's path reaches at or after the start,
+// relative to the rock. worked out from the first path and one that isn't parallel
+// to it, then checked against them all
+fn crossing(lines: &[Line<I128Vec3>], rock_vel: I128Vec3) -> Crossing {
+    let rel = |line: &Line<I128Vec3>| line.vel - rock_vel;
+    let (first, first_vel) = (&lines[0], rel(&lines[0]));
+    let Some((other, normal)) = lines[1..]
+        .iter()
+        .map(|line| (line, first_vel.cross(rel(line))))
+        .find(|&(_, normal)| normal != I128Vec3::ZERO)
+    else {
+        return Crossing::Unknown;
+    };
+    // first.pos + t first_vel = other.pos + s other_vel, which gives
+    // t = ((other.pos - first.pos) x other_vel) . normal / normal . normal
+    let num = (other.pos - first.pos).cross(rel(other)).dot(normal);
+    let den = normal.dot(normal);
+    let scaled = [0, 1, 2].map(|axis| first.pos[axis] * den + num * first_vel[axis]);
+    if scaled.iter().any(|coord| coord % den != 0) {
+        return Crossing::Nowhere;
+    }
+    let pos = i128vec3(scaled[0] / den, scaled[1] / den, scaled[2] / den);
+    let reached = lines.iter().all(|line| {
+        let (gap, vel) = (pos - line.pos, rel(line));
+        match vel == I128Vec3::ZERO {
+            true => gap == I128Vec3::ZERO,
+            false => gap.cross(vel) == I128Vec3::ZERO && gap.dot(vel) >= 0,
+        }
+    });
+    match reached {
+        true => Crossing::At(pos),
+        false => Crossing::Nowhere,
+    }
+}
+
+// tests pairs of paths for crossing with fractions kept exact for part a. for part
+// b, tries every rock velocity up to MAX_SPEED on each axis, ruling most out from
+// just x and y, until one has every path cross at the same point, which is where
+// the rock starts
+pub fn reference(part: Part, example: &Example) -> Result<Answer, Error> {
+    const MAX_SPEED: i128 = 500;
+    let lines = get_lines(&example.input)?;
+    match part {
+        Part::A => {
+            let param = |name, default: i128| example.param_or(name, default as i64) as i128;
+            let area = param("min", *AREA.start())..=param("max", *AREA.end());
+            let crosses = lines
+                .iter()
+                .tuple_combinations()
+                .filter(|(l1, l2)| {
+                    let (p1, v1, p2, v2) = (l1.pos, l1.vel, l2.pos, l2.vel);
+                    // p1 + t v1 = p2 + s v2 in x and y, solved with t = t_num / div
+                    // and s = s_num / div
+                    let div = v2.x * v1.y - v1.x * v2.y;
+                    if div == 0 {
+                        return false;
+                    }
+                    let gap = p2 - p1;
+                    let (t_num, s_num) = (v2.x * gap.y - gap.x * v2.y, v1.x * gap.y - v1.y * gap.x);
+                    let sign = div.signum();
+                    let (div, t_num, s_num) = (div * sign, t_num * sign, s_num * sign);
+                    // where they cross is p1 + t v1, scaled up by div to stay whole
+                    let within = |pos: i128, vel: i128| {
+                        (area.start() * div..=area.end() * div).contains(&(pos * div + t_num * vel))
+                    };
+                    t_num > 0 && s_num > 0 && within(p1.x, v1.x) && within(p1.y, v1.y)
+                })
+                .count();
+            Ok(crosses.into())
+        }
+        Part::B => {
+            // as many as the solution needs, as two or three may not pin down one
+            // rock, and so diff doesn't shrink inputs to ones only this answers
+            if lines.len() < 5 {
+                bail!("need at least 5 hailstones, not {}", lines.len());
+            }
+            let flat = lines
+                .iter()
+                .map(|line| Line {
+                    pos: i128vec3(line.pos.x, line.pos.y, 0),
+                    vel: i128vec3(line.vel.x, line.vel.y, 0),
+                })
+                .collect_vec();
+            let speeds = || -MAX_SPEED..=MAX_SPEED;
+            for (x, y) in speeds().cartesian_product(speeds()) {
+                if matches!(crossing(&flat, i128vec3(x, y, 0)), Crossing::Nowhere) {
+                    continue;
+                }
+                for z in speeds() {
+                    if let Crossing::At(pos) = crossing(&lines, i128vec3(x, y, z)) {
+                        return Ok((pos.x + pos.y + pos.z).into());
+                    }
+                }
+            }
+            bail!("no rock moving at most {} on each axis hits every hailstone", MAX_SPEED)
+        }
+    }
+}
+
+#[tracing::instrument]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "19, 13, 30 @ -2,  1, -2
+    18, 19, 22 @ -1, -1, -2
+    20, 25, 34 @ -2, -2, -4
+    12, 31, 28 @ -1, -2, -1
+    20, 19, 15 @  1, -5, -3";
+    Ok(vec![Example::new("example", input)
+        .with_param("min", 7)
+        .with_param("max", 27)
+        .part_a("2")
+        .part_b("47")])
+}
+
+pub fn solve_example(part: Part, example: &Example) -> Result<Answer, Error> {
+    let lines = get_lines(&example.input)?;
+    Ok(match part {
+        Part::A => {
+            let param = |name, default: i128| example.param_or(name, default as i64) as i128;
+            let area = param("min", *AREA.start())..=param("max", *AREA.end());
+            count_intersections(&lines, &area).into()
+        }
+        Part::B => find_rock(&lines)?.into(),
+    })
+}
+
+// hailstones that a rock thrown from somewhere in the test area will hit, each at
+// a different time
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let area = || 200_000_000_000_000..=400_000_000_000_000;
+    let rock_pos = I64Vec3::new(rng.i64(area()), rng.i64(area()), rng.i64(area()));
+    let rock_vel = I64Vec3::new(rng.i64(-300..=300), rng.i64(-300..=300), rng.i64(-300..=300));
+    let mut vels = FxHashSet::default();
+    let mut times = FxHashSet::default();
+    (0..size.max(5))
+        .map(|_| {
+            let vel = loop {
+                let vel = I64Vec3::new(rng.i64(-300..=300), rng.i64(-300..=300), rng.i64(-300..=300));
+                if vel != rock_vel && vels.insert(vel.to_array()) {
+                    break vel;
+                }
+            };
+            let time = loop {
+                let time = rng.i64(10_000_000_000..1_000_000_000_000);
+                if times.insert(time) {
+                    break time;
+                }
+            };
+            let pos = rock_pos + (rock_vel - vel) * time;
+            format!(
+                "{}, {}, {} @ {}, {}, {}\n",
+                pos.x, pos.y, pos.z, vel.x, vel.y, vel.z
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,141 @@
+use std::collections::BinaryHeap;
+
+use anyhow::Error;
+use fastrand::Rng;
+use itertools::Itertools;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{fixtures::Example, gen, input::{non_empty_lines, ParseError}, NoPartB};
+
+// weighted adjacency, with components numbered in order of first appearance
+type Graph = Vec<FxHashMap<usize, u32>>;
+
+fn parse(input: &str) -> Result<Graph, ParseError> {
+    let mut ids = FxHashMap::default();
+    let mut id = |name| {
+        let next = ids.len();
+        *ids.entry(name).or_insert(next)
+    };
+    let mut wires = vec![];
+    for line in non_empty_lines(input) {
+        let (from, tos) = line.split_once(line.text, ":")?;
+        let from = id(from);
+        for to in tos.split_whitespace() {
+            wires.push((from, id(to)));
+        }
+    }
+
+    let mut graph = vec![FxHashMap::default(); ids.len()];
+    for (from, to) in wires {
+        graph[from].insert(to, 1);
+        graph[to].insert(from, 1);
+    }
+    Ok(graph)
+}
+
+// stoer-wagner, returning the weight of the global minimum cut and how many
+// components are on one side of it
+fn min_cut(mut graph: Graph) -> (u32, usize) {
+    let len = graph.len();
+    let mut sizes = vec![1; len];
+    let mut merged = vec![false; len];
+    let mut best = (u32::MAX, 0);
+    for _ in 1..len {
+        // add the most tightly connected node each step, the last two added get merged
+        let start = (0..len).find(|&node| !merged[node]).unwrap();
+        let mut weights = vec![0; len];
+        let mut added = vec![false; len];
+        let mut heap = BinaryHeap::from([(0, start)]);
+        let (mut prev, mut last, mut cut) = (start, start, 0);
+        while let Some((weight, node)) = heap.pop() {
+            if added[node] || weight != weights[node] {
+                continue;
+            }
+            added[node] = true;
+            (prev, last, cut) = (last, node, weight);
+            for (&next, &wires) in graph[node].iter() {
+                if !added[next] {
+                    weights[next] += wires;
+                    heap.push((weights[next], next));
+                }
+            }
+        }
+        tracing::debug!("phase cut {} of size {}", cut, sizes[last]);
+        if cut < best.0 {
+            best = (cut, sizes[last]);
+        }
+
+        merged[last] = true;
+        sizes[prev] += sizes[last];
+        for (next, wires) in std::mem::take(&mut graph[last]) {
+            graph[next].remove(&last);
+            if next != prev {
+                *graph[prev].entry(next).or_default() += wires;
+                *graph[next].entry(prev).or_default() += wires;
+            }
+        }
+    }
+    best
+}
+
+#[tracing::instrument(skip(input), fields(day = 25))]
+pub fn part_a(input: &str) -> Result<usize, Error> {
+    let graph = parse(input)?;
+    let len = graph.len();
+    let (cut, size) = min_cut(graph);
+    tracing::debug!("cut {} wires", cut);
+    Ok(size * (len - size))
+}
+
+// there's no puzzle for part b, just the button to push once every other star is in
+#[tracing::instrument(skip(_input), fields(day = 25))]
+pub fn part_b(_input: &str) -> Result<usize, Error> {
+    Err(NoPartB.into())
+}
+
+#[tracing::instrument]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    crate::fixtures::load(25, 2023)
+}
+
+// two groups of components, each wired tightly enough that the only three wires to
+// cut are the ones between them
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut taken = FxHashSet::default();
+    let mut groups = [(); 2].map(|_| {
+        (0..size.max(8))
+            .map(|_| gen::name(rng, 3, &mut taken))
+            .collect_vec()
+    });
+    // loops through every component, so cutting a group in two takes more than three wires
+    let mut wires = FxHashSet::default();
+    for group in groups.iter_mut() {
+        for _ in 0..3 {
+            rng.shuffle(group);
+            for (from, to) in group.iter().circular_tuple_windows() {
+                if !wires.contains(&(to.clone(), from.clone())) {
+                    wires.insert((from.clone(), to.clone()));
+                }
+            }
+        }
+    }
+    for _ in 0..3 {
+        loop {
+            let from = groups[0][rng.usize(0..groups[0].len())].clone();
+            let to = groups[1][rng.usize(0..groups[1].len())].clone();
+            if wires.insert((from, to)) {
+                break;
+            }
+        }
+    }
+    // listed from one end, like the real inputs
+    let mut lines = wires
+        .into_iter()
+        .into_group_map()
+        .into_iter()
+        .sorted()
+        .map(|(from, tos)| format!("{from}: {}\n", tos.join(" ")))
+        .collect_vec();
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
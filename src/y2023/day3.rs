@@ -0,0 +1,142 @@
+use anyhow::Error;
+use fastrand::Rng;
+use glam::{ivec2, IVec2};
+use rustc_hash::FxHashMap;
+
+use crate::{fixtures::Example, input::{lines, ParseError}};
+
+struct Num {
+    value: u32,
+    cursor: IVec2,
+}
+
+impl Num {
+    // x of start of number
+    fn start_x(&self) -> i32 {
+        self.cursor.x - (1 + self.value.checked_ilog10().unwrap_or(0) as i32)
+    }
+    // x to the right of the number
+    fn end_x(&self) -> i32 {
+        self.cursor.x
+    }
+}
+
+struct Symbol {
+    ch: char,
+    near_nums: Vec<u32>,
+}
+
+fn parse(input: &str) -> Result<Vec<Symbol>, ParseError> {
+    let mut symbols: FxHashMap<IVec2, Symbol> = FxHashMap::default();
+    let mut nums: Vec<Num> = vec![];
+    for (y, line) in lines(input).enumerate() {
+        let mut acc: Option<u32> = None;
+        for (x, ch) in line.text.chars().enumerate() {
+            if let Some(digit) = ch.to_digit(10) {
+                acc = match acc {
+                    None => Some(digit),
+                    Some(acc_value) => Some(
+                        acc_value
+                            .checked_mul(10)
+                            .and_then(|value| value.checked_add(digit))
+                            .ok_or_else(|| line.error_at(x, "number too big"))?,
+                    ),
+                };
+            } else {
+                let cursor = ivec2(x as i32, y as i32);
+                if let Some(value) = acc {
+                    nums.push(Num { value, cursor });
+                    acc = None;
+                }
+                if ch != '.' {
+                    symbols.insert(
+                        cursor,
+                        Symbol {
+                            ch,
+                            near_nums: vec![],
+                        },
+                    );
+                }
+            }
+        }
+        if let Some(value) = acc {
+            nums.push(Num {
+                value,
+                cursor: ivec2(line.text.chars().count() as i32, y as i32),
+            });
+        }
+    }
+
+    // put all nums into the symbol's vec
+    nums.iter().for_each(|num| {
+        (num.start_x() - 1..num.end_x() + 1).for_each(|x| {
+            (num.cursor.y - 1..=num.cursor.y + 1).for_each(|y| {
+                if let Some(Symbol {
+                    ref mut near_nums, ..
+                }) = symbols.get_mut(&ivec2(x, y))
+                {
+                    near_nums.push(num.value);
+                }
+            })
+        })
+    });
+
+    Ok(symbols.into_values().collect())
+}
+
+#[tracing::instrument(skip(input), fields(day = 3))]
+pub fn part_a(input: &str) -> Result<u32, Error> {
+    Ok(parse(input)?
+        .iter()
+        .flat_map(|symbol| symbol.near_nums.iter())
+        .sum::<u32>())
+}
+
+#[tracing::instrument(skip(input), fields(day = 3))]
+pub fn part_b(input: &str) -> Result<u32, Error> {
+    Ok(parse(input)?
+        .iter()
+        .filter(|symbol| symbol.ch == '*' && symbol.near_nums.len() == 2)
+        .map(|symbol| symbol.near_nums.iter().product::<u32>())
+        .sum::<u32>())
+}
+
+#[tracing::instrument]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "467..114..
+        ...*......
+        ..35..633.
+        ......#...
+        617*......
+        .....+.58.
+        ..592.....
+        ......755.
+        ...$.*....
+        .664.598..";
+    Ok(vec![Example::new("example", input)
+        .part_a("4361")
+        .part_b("467835")])
+}
+
+// a size by size schematic, with numbers never running into each other
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const SYMBOLS: &[u8] = b"*#+$/@=%&-";
+    (0..size)
+        .map(|_| {
+            let mut row = String::new();
+            while row.len() < size {
+                let left = size - row.len();
+                match rng.usize(0..20) {
+                    0..=2 if left >= 2 => {
+                        let digits = rng.usize(1..=3.min(left - 1));
+                        let num = rng.u32(10u32.pow(digits as u32 - 1)..10u32.pow(digits as u32));
+                        row.push_str(&format!("{num}."));
+                    }
+                    3 => row.push(SYMBOLS[rng.usize(0..SYMBOLS.len())] as char),
+                    _ => row.push('.'),
+                }
+            }
+            row + "\n"
+        })
+        .collect()
+}
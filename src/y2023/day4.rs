@@ -0,0 +1,85 @@
+use anyhow::Error;
+use fastrand::Rng;
+use itertools::Itertools;
+
+use crate::{fixtures::Example, input::{non_empty_lines, ParseError}};
+
+fn get_matches(input: &str) -> Result<Vec<u32>, ParseError> {
+    non_empty_lines(input)
+        .map(|line| {
+            let (_, card) = line.split_once(line.text, ": ")?;
+            let (winning, held) = line.split_once(card, "|")?;
+            let get_nums = |nums: &str| {
+                nums.split_ascii_whitespace()
+                    .map(|num| line.parse::<u32>(num))
+                    .collect::<Result<Vec<_>, _>>()
+            };
+            let winning_nums = get_nums(winning)?;
+            let held_nums = get_nums(held)?;
+            Ok(winning_nums
+                .iter()
+                .filter(|winning_num| held_nums.contains(winning_num))
+                .count() as u32)
+        })
+        .collect()
+}
+
+#[tracing::instrument(skip(input), fields(day = 4))]
+pub fn part_a(input: &str) -> Result<u32, Error> {
+    Ok(get_matches(input)?
+        .into_iter()
+        .map(|matches| (1 << matches) / 2)
+        .sum())
+}
+
+#[tracing::instrument(skip(input), fields(day = 4))]
+pub fn part_b(input: &str) -> Result<u32, Error> {
+    let (part_b, _) = get_matches(input)?.into_iter().fold(
+        (0u32, Vec::<(u32, u32)>::default()),
+        |(part_b, mut extra_cards), matches| {
+            let total_copies = extra_cards.iter().map(|(copies, _)| copies).sum::<u32>() + 1;
+            extra_cards = extra_cards
+                .iter()
+                .map(|&(copies, num)| (copies, num - 1))
+                .filter(|&(_, num)| num > 0)
+                .collect();
+            if matches > 0 {
+                extra_cards.push((total_copies, matches));
+            }
+            (part_b + total_copies, extra_cards)
+        },
+    );
+    Ok(part_b)
+}
+
+#[tracing::instrument]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+    Ok(vec![Example::new("example", input)
+        .part_a("13")
+        .part_b("30")])
+}
+
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let numbers = |nums: &[u32]| nums.iter().map(|num| format!("{num:>2}")).join(" ");
+    (1..=size)
+        .map(|card| {
+            let mut pool = (1..100).collect_vec();
+            rng.shuffle(&mut pool);
+            let (winning, rest) = pool.split_at(5);
+            let mut have = winning[..rng.usize(0..=5)].to_vec();
+            have.extend(&rest[..8 - have.len()]);
+            rng.shuffle(&mut have);
+            format!(
+                "Card {card:>3}: {} | {}\n",
+                numbers(winning),
+                numbers(&have)
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,238 @@
+use std::ops::Range;
+use anyhow::{anyhow, bail, Error};
+use fastrand::Rng;
+use itertools::{min, Itertools};
+
+use crate::{fixtures::Example, input::{lines, unexpected_end, ParseError}, Part};
+
+type TransformStep = Vec<(i64, Range<i64>)>;
+
+fn parse(input: &str) -> Result<(Vec<i64>, Vec<TransformStep>), ParseError> {
+    let mut lines = lines(input);
+
+    let line = lines
+        .next()
+        .ok_or_else(|| unexpected_end(input, "expected seeds"))?;
+    let (_, seeds) = line.split_once(line.text, ": ")?;
+    let seeds = seeds
+        .split_ascii_whitespace()
+        .map(|s| line.parse::<i64>(s))
+        .collect::<Result<Vec<_>, _>>()?;
+    tracing::debug!("nums {:?}", seeds);
+
+    let mut transform_steps: Vec<TransformStep> = vec![];
+    for line in lines {
+        if line.text.is_empty() || line.text.ends_with(':') {
+            if transform_steps
+                .iter()
+                .last()
+                .map(|last_line| !last_line.is_empty())
+                .unwrap_or(true)
+            {
+                transform_steps.push(vec![]);
+            }
+        } else {
+            let (dest, start, count) = line
+                .text
+                .split_ascii_whitespace()
+                .map(|s| line.parse::<i64>(s))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .collect_tuple()
+                .ok_or_else(|| line.error(line.text, "expected destination, source and length"))?;
+            transform_steps
+                .iter_mut()
+                .last()
+                .ok_or_else(|| line.error(line.text, "expected a map first"))?
+                .push((dest - start, start..(start + count)));
+        }
+    }
+    tracing::debug!("transform_steps {:?}", transform_steps);
+
+    // sort the transforms within a step by start (so can go through in order)
+    transform_steps.iter_mut().for_each(|transform_step| {
+        transform_step.sort_by_key(|(_, r)| r.start);
+    });
+
+    Ok((seeds, transform_steps))
+}
+
+#[tracing::instrument(skip(input), fields(day = 5))]
+pub fn part_a(input: &str) -> Result<i64, Error> {
+    let (seeds, transform_steps) = parse(input)?;
+    let mut nums = seeds;
+    transform_steps.iter().for_each(|transforms| {
+        nums.iter_mut().for_each(|num| {
+            if let Some((offset, _)) = transforms.iter().find(|(_, range)| range.contains(num)) {
+                *num += offset;
+            }
+        });
+    });
+    tracing::debug!("transformed nums {:?}", nums);
+    min(nums).ok_or_else(|| anyhow!("no seeds"))
+}
+
+#[tracing::instrument(skip(input), fields(day = 5))]
+pub fn part_b(input: &str) -> Result<i64, Error> {
+    let (seeds, transform_steps) = parse(input)?;
+    if seeds.len() % 2 != 0 {
+        bail!("seeds should be in start and length pairs");
+    }
+    let mut seed_ranges = seeds.chunks(2).map(|r| r[0]..(r[0] + r[1])).collect_vec();
+    tracing::debug!("ranges {:?}", seed_ranges);
+
+    for transform_step in transform_steps {
+        let mut next_seed_ranges = vec![];
+        for mut seed_range in seed_ranges {
+            for (offset, transform_range) in transform_step.iter() {
+                // any seeds to left of this transform range add unchanged
+                // and crop the seed range to the transform start
+                if seed_range.start < transform_range.start {
+                    let unchanged_range = seed_range.start..transform_range.start.min(seed_range.end);
+                    seed_range.start = unchanged_range.end;
+                    next_seed_ranges.push(unchanged_range);
+                }
+                // any intersect with the transformed range, crop the seed range 
+                // to the end of the transform range
+                let intersect_start = seed_range.start.max(transform_range.start);
+                let intersect_end = seed_range.end.min(transform_range.end);
+                if intersect_end > intersect_start {
+                    seed_range.start = intersect_end;
+                    next_seed_ranges.push((intersect_start + offset)..(intersect_end + offset));
+                }
+                // if nothing left then finish this transform step
+                if seed_range.start == seed_range.end {
+                    break;
+                }
+            }
+            // if anything was left then add it unchanged
+            if seed_range.end > seed_range.start {
+                next_seed_ranges.push(seed_range);
+            }
+        }
+        seed_ranges = next_seed_ranges;
+    }
+    min(seed_ranges.iter().map(|r| r.start)).ok_or_else(|| anyhow!("no seeds"))
+}
+
+// maps every seed on its own through each map, rather than splitting ranges of
+// them. only for up to a few million seeds
+pub fn reference(part: Part, example: &Example) -> Result<i64, Error> {
+    const MAX_SEEDS: i64 = 1 << 24;
+    let (seeds, transform_steps) = parse(&example.input)?;
+    let seed_ranges = match part {
+        Part::A => seeds.iter().map(|&seed| seed..seed + 1).collect_vec(),
+        Part::B => {
+            if seeds.len() % 2 != 0 {
+                bail!("seeds should be in start and length pairs");
+            }
+            seeds.chunks(2).map(|pair| pair[0]..pair[0] + pair[1]).collect_vec()
+        }
+    };
+    let count = seed_ranges
+        .iter()
+        .map(|range| (range.end - range.start).max(0))
+        .sum::<i64>();
+    if count > MAX_SEEDS {
+        bail!("too many seeds for the reference ({}), at most {}", count, MAX_SEEDS);
+    }
+    seed_ranges
+        .into_iter()
+        .flatten()
+        .map(|seed| {
+            transform_steps.iter().fold(seed, |num, transforms| {
+                transforms
+                    .iter()
+                    .find(|(_, range)| range.contains(&num))
+                    .map_or(num, |(offset, _)| num + offset)
+            })
+        })
+        .min()
+        .ok_or_else(|| anyhow!("no seeds"))
+}
+
+#[tracing::instrument]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "seeds: 79 14 55 13
+
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+        
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+        
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+        
+        water-to-light map:
+        88 18 7
+        18 25 70
+        
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+        
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+        
+        humidity-to-location map:
+        60 56 37
+        56 93 4";
+    Ok(vec![Example::new("example", input)
+        .part_a("35")
+        .part_b("46")])
+}
+
+// each map is a shuffle of ranges covering the same span, so nothing is mapped twice
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const NAMES: [&str; 8] = [
+        "seed",
+        "soil",
+        "fertilizer",
+        "water",
+        "light",
+        "temperature",
+        "humidity",
+        "location",
+    ];
+    let span = 1000 * size as i64;
+    let seeds = (0..size.div_ceil(5))
+        .flat_map(|_| {
+            let start = rng.i64(0..span);
+            [start, rng.i64(1..=span - start)]
+        })
+        .join(" ");
+    let maps = NAMES
+        .iter()
+        .tuple_windows()
+        .map(|(from, to)| {
+            let mut cuts = (0..rng.usize(1..=size))
+                .map(|_| rng.i64(1..span))
+                .chain([0, span])
+                .sorted()
+                .dedup()
+                .tuple_windows()
+                .collect_vec();
+            rng.shuffle(&mut cuts);
+            let mut dest = 0;
+            let ranges = cuts
+                .iter()
+                .map(|&(start, end)| {
+                    let range = format!("{} {} {}", dest, start, end - start);
+                    dest += end - start;
+                    range
+                })
+                .join("\n");
+            format!("{from}-to-{to} map:\n{ranges}\n")
+        })
+        .join("\n");
+    format!("seeds: {seeds}\n\n{maps}")
+}
@@ -0,0 +1,105 @@
+use anyhow::Error;
+use fastrand::Rng;
+use itertools::Itertools;
+
+use crate::{
+    fixtures::Example,
+    input::{non_empty_lines, unexpected_end, ParseError},
+    Part,
+};
+
+fn parse(input: &str) -> Result<(Vec<f64>, Vec<f64>), ParseError> {
+    let mut lines = non_empty_lines(input);
+    let mut read_nums = |name: &str| {
+        let line = lines
+            .next()
+            .ok_or_else(|| unexpected_end(input, format!("expected '{}'", name)))?;
+        line.strip_prefix(line.text, name)?
+            .split_ascii_whitespace()
+            .map(|s| line.parse::<u64>(s).map(|num| num as f64))
+            .collect::<Result<Vec<_>, _>>()
+    };
+    let (times, distances) = (read_nums("Time:")?, read_nums("Distance:")?);
+    tracing::debug!("times {:?} distances {:?}", times, distances);
+    Ok((times, distances))
+}
+
+fn calc(time: f64, distance: f64) -> f64 {
+    let sqrt_dis = f64::sqrt(time * time - 4.0 * distance);
+    let min = f64::floor(1.0 + 0.5 * (time - sqrt_dis));
+    let max = f64::ceil(-1.0 + 0.5 * (time + sqrt_dis));
+    1.0 + (max - min)
+}
+
+#[tracing::instrument(skip(input), fields(day = 6))]
+pub fn part_a(input: &str) -> Result<u64, Error> {
+    let (times, distances) = parse(input)?;
+    Ok(times
+        .iter()
+        .zip(distances.iter())
+        .map(|(&time, &distance)| calc(time, distance))
+        .product::<f64>() as u64)
+}
+
+#[tracing::instrument(skip(input), fields(day = 6))]
+pub fn part_b(input: &str) -> Result<u64, Error> {
+    let (times, distances) = parse(input)?;
+    // shifted by their digit count, which log10 gets wrong for 1, 10, 100...
+    let fold_nums = |nums: Vec<f64>| {
+        nums.iter().fold(0f64, |agg, &v| {
+            agg * 10f64.powi((v as u64).to_string().len() as i32) + v
+        })
+    };
+    Ok(calc(fold_nums(times), fold_nums(distances)) as u64)
+}
+
+// every way to hold the button, and the numbers joined up as text for part b
+pub fn reference(part: Part, example: &Example) -> Result<u64, Error> {
+    let (times, distances) = parse(&example.input)?;
+    let races = match part {
+        Part::A => times
+            .iter()
+            .zip(distances.iter())
+            .map(|(&time, &distance)| (time as u64, distance as u64))
+            .collect_vec(),
+        Part::B => {
+            let join = |nums: &[f64]| nums.iter().map(|&num| num as u64).join("").parse();
+            vec![(join(&times)?, join(&distances)?)]
+        }
+    };
+    Ok(races
+        .iter()
+        .map(|&(time, distance)| {
+            (0..=time)
+                .filter(|hold| hold * (time - hold) > distance)
+                .count() as u64
+        })
+        .product())
+}
+
+#[tracing::instrument]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "Time:      7  15   30
+        Distance:  9  40  200";
+    Ok(vec![Example::new("example", input)
+        .part_a("288")
+        .part_b("71503")])
+}
+
+// at most 4 races, so part b's joined up numbers still fit
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let races = (0..size.min(4))
+        .map(|_| {
+            let time = rng.u64(7..100);
+            (time, rng.u64(0..(time / 2) * (time - time / 2)))
+        })
+        .collect_vec();
+    let row = |name: &str, nums: Vec<u64>| {
+        format!(
+            "{name:<10}{}\n",
+            nums.iter().map(|num| format!("{num:>4}")).join(" ")
+        )
+    };
+    row("Time:", races.iter().map(|race| race.0).collect())
+        + &row("Distance:", races.iter().map(|race| race.1).collect())
+}
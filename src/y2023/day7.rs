@@ -1,32 +1,48 @@
+use anyhow::Error;
+use fastrand::Rng;
 use itertools::Itertools;
+use rustc_hash::FxHashSet;
 use std::ops::Range;
 
+use crate::{fixtures::Example, input::{non_empty_lines, ParseError}};
+
 #[derive(Debug)]
 struct Hand {
     bid: u32,
     score: u32,
 }
 
-#[tracing::instrument(skip(input), fields(day = 7))]
-pub fn solve(input: &str) -> String {
-    let hands = input
-        .lines()
+fn parse(input: &str) -> Result<Vec<([char; 5], u32)>, ParseError> {
+    non_empty_lines(input)
         .map(|line| {
-            let line = line.trim_start();
-            let cards: [char; 5] = line.trim_start()[0..5]
+            let (cards, bid) = line.split_once(line.text, " ")?;
+            if let Some((index, ch)) = cards
+                .char_indices()
+                .find(|&(_, ch)| !"23456789TJQKA".contains(ch))
+            {
+                return Err(line.error(&cards[index..], format!("unknown card '{}'", ch)));
+            }
+            let cards: [char; 5] = cards
                 .chars()
                 .collect_vec()
                 .try_into()
-                .unwrap();
-            let bid = line[6..].parse::<u32>().unwrap();
-            (cards, bid)
+                .map_err(|_| line.error(cards, "expected 5 cards"))?;
+            Ok((cards, line.parse(bid)?))
         })
-        .collect_vec();
+        .collect()
+}
 
-    format!("{}/{}", part_a(&hands), part_b(&hands))
+#[tracing::instrument(skip(input), fields(day = 7))]
+pub fn part_a(input: &str) -> Result<u32, Error> {
+    Ok(score_hands(&parse(input)?))
 }
 
-fn part_a(hands: &[([char; 5], u32)]) -> u32 {
+#[tracing::instrument(skip(input), fields(day = 7))]
+pub fn part_b(input: &str) -> Result<u32, Error> {
+    Ok(score_hands_with_jokers(&parse(input)?))
+}
+
+fn score_hands(hands: &[([char; 5], u32)]) -> u32 {
     let card_types = "23456789TJQKA".chars().collect_vec().try_into().unwrap();
     let mut hands = hands
         .iter()
@@ -41,7 +57,7 @@ fn part_a(hands: &[([char; 5], u32)]) -> u32 {
     sort_by_score_and_sum(&mut hands)
 }
 
-fn part_b(hands: &[([char; 5], u32)]) -> u32 {
+fn score_hands_with_jokers(hands: &[([char; 5], u32)]) -> u32 {
     let card_types = "J23456789TQKA".chars().collect_vec().try_into().unwrap();
     let mut hands = hands
         .iter()
@@ -85,7 +101,7 @@ fn get_card_counts(card_indices: &[usize; 5], card_range: Range<usize>) -> Vec<u
     counts
 }
 
-fn get_hand_score(card_indices: &[usize; 5], counts: &Vec<usize>) -> u32 {
+fn get_hand_score(card_indices: &[usize; 5], counts: &[usize]) -> u32 {
     ((counts.len() + 2 * *counts.first().unwrap_or(&0)) as u32) * 13_u32.pow(5)
         + card_indices
             .iter()
@@ -102,15 +118,32 @@ fn sort_by_score_and_sum(hands: &mut [Hand]) -> u32 {
 }
 
 #[tracing::instrument]
-pub fn test() -> (String, String) {
-    (
-        solve(
-            "32T3K 765
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "32T3K 765
         T55J5 684
         KK677 28
         KTJJT 220
-        QQQJA 483",
-        ),
-        "6440/5905".into(),
-    )
+        QQQJA 483";
+    Ok(vec![Example::new("example", input)
+        .part_a("6440")
+        .part_b("5905")])
+}
+
+// no hand twice, so there are no ties to rank
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const CARDS: &[u8] = b"23456789TJQKA";
+    let mut hands = FxHashSet::default();
+    while hands.len() < size {
+        hands.insert(
+            (0..5)
+                .map(|_| CARDS[rng.usize(0..CARDS.len())] as char)
+                .collect::<String>(),
+        );
+    }
+    let mut hands = hands.into_iter().collect_vec();
+    rng.shuffle(&mut hands);
+    hands
+        .iter()
+        .map(|hand| format!("{hand} {}\n", rng.u32(1..=1000)))
+        .collect()
 }
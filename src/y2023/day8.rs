@@ -0,0 +1,179 @@
+use anyhow::{anyhow, bail, Error};
+use fastrand::Rng;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{fixtures::Example, input::{non_empty_lines, unexpected_end, ParseError}, Part};
+
+type Moves<'a> = FxHashMap<&'a str, [&'a str; 2]>;
+
+fn get_data(input: &str) -> Result<(Vec<usize>, Moves<'_>), ParseError> {
+    let mut lines = non_empty_lines(input);
+
+    let line = lines
+        .next()
+        .ok_or_else(|| unexpected_end(input, "expected instructions"))?;
+    let instructions = line
+        .text
+        .char_indices()
+        .map(|(index, ch)| match ch {
+            'L' => Ok(0),
+            'R' => Ok(1),
+            _ => Err(line.error_at(index, format!("expected 'L' or 'R', not '{}'", ch))),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    tracing::debug!("{:?}", instructions);
+
+    let moves = lines
+        .map(|line| {
+            let (from, to) = line.split_once(line.text, " = ")?;
+            let to = line.strip_suffix(line.strip_prefix(to, "(")?, ")")?;
+            let (left, right) = line.split_once(to, ", ")?;
+            Ok((from, [left, right]))
+        })
+        .collect::<Result<Moves, ParseError>>()?;
+    tracing::debug!("{:?}", moves);
+
+    Ok((instructions, moves))
+}
+
+#[tracing::instrument(skip(input), fields(day = 8))]
+pub fn part_a(input: &str) -> Result<usize, Error> {
+    let (instructions, moves) = get_data(input)?;
+    run("AAA", &instructions, &moves)
+}
+
+#[tracing::instrument(skip(input), fields(day = 8))]
+pub fn part_b(input: &str) -> Result<usize, Error> {
+    let (instructions, moves) = get_data(input)?;
+    let steps = moves
+        .keys()
+        .filter(|key| key.ends_with('A'))
+        .map(|key| run(key, &instructions, &moves))
+        .collect::<Result<Vec<_>, _>>()?;
+    if steps.is_empty() {
+        bail!("no nodes ending in A");
+    }
+    Ok(crate::utils::lcm(&steps))
+}
+
+fn run(start: &str, instructions: &[usize], moves: &Moves) -> Result<usize, Error> {
+    // by then it's been on some node at the same point in the instructions twice,
+    // so it's going round a loop without a Z node
+    let max_steps = moves.len() * instructions.len();
+    let mut current = start;
+    let mut index = 0;
+    while !current.ends_with('Z') {
+        if index > max_steps {
+            bail!("{} never gets to a node ending in Z", start);
+        }
+        let next = moves
+            .get(current)
+            .ok_or_else(|| anyhow!("no move from {}", current))?;
+        current = next[instructions[index % instructions.len()]];
+        index += 1;
+    }
+    Ok(index)
+}
+
+// walks every ghost a step at a time until they're all on a Z node at once, rather
+// than assuming each goes round a loop that ends on its Z node. only for answers of
+// up to a few million steps
+pub fn reference(part: Part, example: &Example) -> Result<usize, Error> {
+    const MAX_STEPS: usize = 1 << 24;
+    let (instructions, moves) = get_data(&example.input)?;
+    let (mut ghosts, at_end): (Vec<&str>, fn(&str) -> bool) = match part {
+        Part::A => (vec!["AAA"], |node| node == "ZZZ"),
+        Part::B => (
+            moves.keys().copied().filter(|key| key.ends_with('A')).collect(),
+            |node| node.ends_with('Z'),
+        ),
+    };
+    if ghosts.is_empty() {
+        bail!("no nodes ending in A");
+    }
+    for step in 0..MAX_STEPS {
+        if ghosts.iter().all(|ghost| at_end(ghost)) {
+            return Ok(step);
+        }
+        for ghost in ghosts.iter_mut() {
+            let next = moves
+                .get(ghost)
+                .ok_or_else(|| anyhow!("no move from {}", ghost))?;
+            *ghost = next[instructions[step % instructions.len()]];
+        }
+    }
+    bail!("the ghosts weren't all on Z nodes in {} steps", MAX_STEPS)
+}
+
+#[tracing::instrument]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    Ok(vec![
+        Example::new(
+            "repeating",
+            "LLR
+
+    AAA = (BBB, BBB)
+    BBB = (AAA, ZZZ)
+    ZZZ = (ZZZ, ZZZ)",
+        )
+        .part_a("6"),
+        Example::new(
+            "ghosts",
+            "LR
+
+    11A = (11B, XXX)
+    11B = (XXX, 11Z)
+    11Z = (11B, XXX)
+    22A = (22B, XXX)
+    22B = (22C, 22C)
+    22C = (22Z, 22Z)
+    22Z = (22B, 22B)
+    XXX = (XXX, XXX)",
+        )
+        .part_b("6"),
+    ])
+}
+
+// a ghost for each start, going round a loop that's a different prime number of
+// times as long as the moves and ending on its Z node, as the real inputs do. the
+// way not taken from each node goes somewhere else on its loop
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    const PRIMES: [usize; 5] = [3, 5, 7, 11, 13];
+    let moves = (0..size.clamp(2, 300))
+        .map(|_| if rng.bool() { 'L' } else { 'R' })
+        .collect::<String>();
+    let mut taken = FxHashSet::from_iter(["AAA".to_string(), "ZZZ".to_string()]);
+    let mut name = |rng: &mut Rng, last: Option<char>| loop {
+        let mut name = (0..2).map(|_| rng.uppercase()).collect::<String>();
+        name.push(last.unwrap_or_else(|| rng.char('B'..='Y')));
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    };
+    let mut nodes = vec![];
+    for (ghost, prime) in PRIMES[..rng.usize(2..=PRIMES.len())].iter().enumerate() {
+        let len = moves.len() * prime;
+        let mut path = vec![if ghost == 0 {
+            "AAA".into()
+        } else {
+            name(rng, Some('A'))
+        }];
+        path.extend((1..len).map(|_| name(rng, None)));
+        path.push(if ghost == 0 {
+            "ZZZ".into()
+        } else {
+            name(rng, Some('Z'))
+        });
+        for (step, node) in path.iter().enumerate() {
+            // the Z node carries on round the loop the same way as the start
+            let next = &path[if step == len { 1 } else { step + 1 }];
+            let other = &path[rng.usize(1..len)];
+            nodes.push(match moves.as_bytes()[step % moves.len()] {
+                b'L' => format!("{node} = ({next}, {other})"),
+                _ => format!("{node} = ({other}, {next})"),
+            });
+        }
+    }
+    rng.shuffle(&mut nodes);
+    format!("{moves}\n\n{}\n", nodes.join("\n"))
+}
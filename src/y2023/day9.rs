@@ -0,0 +1,71 @@
+use anyhow::Error;
+use fastrand::Rng;
+use glam::{ivec2, IVec2};
+use itertools::Itertools;
+
+use crate::{fixtures::Example, input::{non_empty_lines, ParseError}};
+
+fn extrapolate(input: &str) -> Result<IVec2, ParseError> {
+    non_empty_lines(input)
+        .map(|line| {
+            line.text
+                .split_ascii_whitespace()
+                .map(|n| line.parse::<i32>(n))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .fold_ok(IVec2::ZERO, |acc, line| acc + get_next(line))
+}
+
+#[tracing::instrument(skip(input), fields(day = 9))]
+pub fn part_a(input: &str) -> Result<i32, Error> {
+    Ok(extrapolate(input)?.x)
+}
+
+#[tracing::instrument(skip(input), fields(day = 9))]
+pub fn part_b(input: &str) -> Result<i32, Error> {
+    Ok(extrapolate(input)?.y)
+}
+
+fn get_next(line: Vec<i32>) -> IVec2 {
+    if line.iter().all(|&val| val == 0) {
+        IVec2::ZERO
+    } else {
+        ivec2(line[line.len() - 1], line[0])
+            + ivec2(1, -1)
+                * get_next(
+                    line.windows(2)
+                        .map(|slice| slice[1] - slice[0])
+                        .collect_vec(),
+                )
+    }
+}
+
+#[tracing::instrument]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    let input = "0 3 6 9 12 15
+        1 3 6 10 15 21
+        10 13 16 21 30 45";
+    Ok(vec![Example::new("example", input)
+        .part_a("114")
+        .part_b("2")])
+}
+
+// polynomials of up to the third degree, so every line extrapolates
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let coefficients = (0..rng.usize(1..=4))
+                .map(|_| rng.i32(-3..=3))
+                .collect_vec();
+            (0..21)
+                .map(|x: i32| {
+                    coefficients
+                        .iter()
+                        .rev()
+                        .fold(0, |value, coefficient| value * x + coefficient)
+                })
+                .join(" ")
+                + "\n"
+        })
+        .collect()
+}
@@ -0,0 +1,34 @@
+add_day!(2023, 1, day1);
+add_day!(2023, 2, day2);
+add_day!(2023, 3, day3);
+add_day!(2023, 4, day4);
+add_day!(2023, 5, day5);
+add_day!(2023, 6, day6);
+add_day!(2023, 7, day7);
+add_day!(2023, 8, day8);
+add_day!(2023, 9, day9);
+add_day!(2023, 10, day10);
+add_day!(2023, 11, day11, params);
+add_day!(2023, 12, day12);
+add_day!(2023, 13, day13);
+add_day!(2023, 14, day14);
+add_day!(2023, 15, day15);
+add_day!(2023, 16, day16);
+add_day!(2023, 17, day17);
+add_day!(2023, 18, day18);
+add_day!(2023, 19, day19);
+add_day!(2023, 20, day20);
+add_day!(2023, 21, day21, params);
+add_day!(2023, 22, day22);
+add_day!(2023, 23, day23);
+add_day!(2023, 24, day24, params);
+add_day!(2023, 25, day25);
+
+add_reference!(2023, 5, day5);
+add_reference!(2023, 6, day6);
+add_reference!(2023, 8, day8);
+add_reference!(2023, 12, day12);
+add_reference!(2023, 14, day14);
+add_reference!(2023, 20, day20);
+add_reference!(2023, 21, day21);
+add_reference!(2023, 24, day24);
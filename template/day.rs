@@ -0,0 +1,26 @@
+use anyhow::Error;
+use fastrand::Rng;
+use itertools::Itertools;
+
+use crate::{fixtures::Example, input::non_empty_lines};
+
+#[tracing::instrument(skip(input), fields(day = DAY))]
+pub fn part_a(input: &str) -> Result<usize, Error> {
+    let lines = non_empty_lines(input).collect_vec();
+    tracing::debug!("input {:?}", lines);
+    Ok(0)
+}
+
+#[tracing::instrument(skip(_input), fields(day = DAY))]
+pub fn part_b(_input: &str) -> Result<usize, Error> {
+    Ok(0)
+}
+
+#[tracing::instrument]
+pub fn examples() -> Result<Vec<Example>, Error> {
+    crate::fixtures::load(DAY, YEAR)
+}
+
+pub fn generate(_rng: &mut Rng, _size: usize) -> String {
+    String::new()
+}
@@ -0,0 +1,43 @@
+// each day's answers for the real input, for days with a cached input and known
+// answers (which aren't checked in, so these pass without them)
+use advent_2023::{answers::AnswerStore, cache::Cache, get_solution, Part};
+use anyhow::Error;
+use std::{fs::read_to_string, path::PathBuf};
+
+fn cache() -> Cache {
+    Cache::new(&std::env::var_os("AOC_CACHE_DIR").map_or("cache".into(), PathBuf::from))
+}
+
+fn real_input(year: u32, day: u32) -> Result<(), Error> {
+    let store = AnswerStore::load(day, year)?;
+    let Ok(input) = read_to_string(cache().input_path(day, year)) else {
+        eprintln!("{year} day {day} has no cached input, skipping");
+        return Ok(());
+    };
+    let solution = get_solution(year, day)?;
+    for part in [Part::A, Part::B] {
+        if let Some((_, expected)) = store.get(part) {
+            let answer = (solution.part(part))(&input)?;
+            assert_eq!(
+                &answer.to_string(),
+                expected,
+                "{year} day {day} part {part}"
+            );
+        }
+    }
+    Ok(())
+}
+
+macro_rules! days {
+    ($($name: ident => ($year: literal, $day: literal)),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() -> Result<(), Error> {
+                real_input($year, $day)
+            }
+        )*
+    };
+}
+
+// a test for each add_day!, listed by build.rs
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
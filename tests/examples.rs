@@ -0,0 +1,35 @@
+// each day's examples, as the runner tests them before solving
+use advent_2023::{fixtures, get_solution, get_years};
+use anyhow::Error;
+
+fn example(year: u32, day: u32) -> Result<(), Error> {
+    let solution = get_solution(year, day)?;
+    fixtures::check(&(solution.examples)()?, solution.solve_example)?;
+    Ok(())
+}
+
+macro_rules! days {
+    ($($name: ident => ($year: literal, $day: literal)),* $(,)?) => {
+        const DAYS: &[(u32, u32)] = &[$(($year, $day)),*];
+        $(
+            #[test]
+            fn $name() -> Result<(), Error> {
+                example($year, $day)
+            }
+        )*
+    };
+}
+
+// a test for each add_day!, listed by build.rs
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+// so build.rs can't miss a registered day
+#[test]
+fn every_day_is_tested() {
+    let missing = get_years()
+        .into_iter()
+        .flat_map(|year| (1..=25).map(move |day| (year, day)))
+        .filter(|&(year, day)| get_solution(year, day).is_ok() && !DAYS.contains(&(year, day)))
+        .collect::<Vec<_>>();
+    assert!(missing.is_empty(), "{missing:?} have no test");
+}
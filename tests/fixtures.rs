@@ -0,0 +1,29 @@
+// fetching a puzzle again adds to its fixture without losing hand edits
+use advent_2023::fixtures::{self, Example};
+
+#[test]
+fn merge_keeps_hand_edits() {
+    let mut examples = vec![
+        Example::new("example 1", "1 2 3\n")
+            .with_param("steps", 6)
+            .part_a(6),
+        Example::new("renamed", "4 5\n").part_a(20).part_b(9),
+    ];
+    let fetched = vec![
+        Example::new("example 1", "1 2 3").part_a(7).part_b(1),
+        Example::new("example 2", "4 5\n").part_a(20).part_b(10),
+        Example::new("example 3", "6\n").part_b(6),
+    ];
+    assert_eq!(fixtures::merge(&mut examples, fetched), 2);
+    assert_eq!(
+        examples,
+        [
+            Example::new("example 1", "1 2 3\n")
+                .with_param("steps", 6)
+                .part_a(6)
+                .part_b(1),
+            Example::new("renamed", "4 5\n").part_a(20).part_b(9),
+            Example::new("example 3", "6\n").part_b(6),
+        ]
+    );
+}
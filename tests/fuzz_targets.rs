@@ -0,0 +1,21 @@
+// the fuzz crate builds separately, so check here it has a target for every day
+use advent_2023::{get_solution, get_years};
+use std::{fs::read_to_string, path::Path};
+
+#[test]
+fn every_day_is_fuzzed() {
+    let manifest = read_to_string("fuzz/Cargo.toml").unwrap();
+    for year in get_years() {
+        let missing = (1..=25)
+            .filter(|&day| get_solution(year, day).is_ok())
+            .filter(|day| {
+                !manifest.contains(&format!("name = \"y{year}_day{day}\""))
+                    || !Path::new(&format!("fuzz/fuzz_targets/y{year}/day{day}.rs")).exists()
+            })
+            .collect::<Vec<_>>();
+        assert!(
+            missing.is_empty(),
+            "{year} days {missing:?} have no fuzz target"
+        );
+    }
+}
@@ -0,0 +1,31 @@
+// each day's generator gives the same input for a seed, and one the day solves
+use advent_2023::{gen, get_solution, NoPartB, Part};
+use anyhow::Error;
+
+#[test]
+fn generated_inputs_solve() -> Result<(), Error> {
+    for day in (1..=25).filter(|&day| get_solution(2023, day).is_ok()) {
+        let solution = get_solution(2023, day)?;
+        for seed in 0..3 {
+            let input = gen::generate(2023, day, seed, 10)?;
+            assert_eq!(
+                input,
+                gen::generate(2023, day, seed, 10)?,
+                "day {day} seed {seed}"
+            );
+            for part in [Part::A, Part::B] {
+                // part b walks hundreds of thousands of pages on a map this small
+                if (day, part) == (21, Part::B) {
+                    continue;
+                }
+                match (solution.part(part))(&input) {
+                    Err(err) if !err.is::<NoPartB>() => {
+                        panic!("day {day} seed {seed} part {part}: {err:#}\n{input}")
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,24 @@
+// the parts of days that take parameters the puzzle text gives more examples of
+use advent_2023::y2023::day11;
+use anyhow::Error;
+
+const GALAXIES: &str = "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+
+#[test]
+fn day11_expansion() -> Result<(), Error> {
+    let (galaxies, empties) = day11::read_data(GALAXIES)?;
+    assert_eq!(
+        day11::calc_dists(&galaxies, &empties, &[1, 2, 10, 100]),
+        [292, 374, 1030, 8410]
+    );
+    Ok(())
+}
@@ -0,0 +1,17 @@
+// each reference solver gets the day's examples right, as it's what diff trusts
+use advent_2023::{fixtures, get_reference, get_solution};
+use anyhow::{Context, Error};
+
+#[test]
+fn references_pass_examples() -> Result<(), Error> {
+    for day in (1..=25).filter(|&day| get_reference(2023, day).is_ok()) {
+        let reference = get_reference(2023, day)?;
+        // day 21's reference takes most of a minute over 5000 steps without optimising
+        let examples = (get_solution(2023, day)?.examples)()?
+            .into_iter()
+            .filter(|example| example.param_or("steps", 0) <= 1000)
+            .collect::<Vec<_>>();
+        fixtures::check(&examples, reference.solve).with_context(|| format!("day {day}"))?;
+    }
+    Ok(())
+}
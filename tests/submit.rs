@@ -0,0 +1,133 @@
+// reading the site's responses to an answer, and ruling answers out before sending them
+use advent_2023::{
+    cache::Cache,
+    verdicts::{parse_cooldown, parse_response, Submission, Verdict, VerdictLog},
+    Part,
+};
+use anyhow::Error;
+
+// the site's page around its response, which is all in the <article>
+fn page(article: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en-us\">\n<head>\n<title>Day 6 - Advent of Code 2023</title>\n</head>\
+         <body>\n<header><h1 class=\"title-global\"><a href=\"/\">Advent of Code</a></h1></header>\n\
+         <main>\n<article><p>{article}</p></article>\n</main>\n</body>\n</html>"
+    )
+}
+
+const CORRECT: &str =
+    "That's the right answer!  You are <span class=\"day-success\">one gold star</span> \
+    closer to restoring snow operations. <a href=\"/2023/day/6#part2\">[Continue to Part Two]</a>";
+const TOO_HIGH: &str = "That's not the right answer; your answer is too high.  If you're stuck, make sure \
+    you're using the full input data; there are also some general tips on the <a href=\"/2023/about\">about \
+    page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" \
+    target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. \
+    <a href=\"/2023/day/6\">[Return to Day 6]</a>";
+const TOO_LOW: &str = "That's not the right answer; your answer is too low.  If you're stuck, make sure \
+    you're using the full input data; there are also some general tips on the <a href=\"/2023/about\">about \
+    page</a>, or you can ask for hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" \
+    target=\"_blank\">subreddit</a>.  Please wait one minute before trying again. \
+    <a href=\"/2023/day/6\">[Return to Day 6]</a>";
+const WRONG: &str = "That's not the right answer.  If you're stuck, make sure you're using the full input \
+    data; there are also some general tips on the <a href=\"/2023/about\">about page</a>, or you can ask for \
+    hints on the <a href=\"https://www.reddit.com/r/adventofcode/\" target=\"_blank\">subreddit</a>.  \
+    Because you have guessed incorrectly 5 times on this puzzle, please wait 5 minutes before trying again. \
+    <a href=\"/2023/day/6\">[Return to Day 6]</a>";
+const WAIT: &str =
+    "You gave an answer too recently; you have to wait after submitting an answer before \
+    trying again.  You have 1m 20s left to wait. <a href=\"/2023/day/6\">[Return to Day 6]</a>";
+const WRONG_LEVEL: &str =
+    "You don't seem to be solving the right level.  Did you already complete it? \
+    <a href=\"/2023/day/6\">[Return to Day 6]</a>";
+
+#[test]
+fn verdicts() -> Result<(), Error> {
+    for (article, verdict, cooldown) in [
+        (CORRECT, Verdict::Correct, 0),
+        (TOO_HIGH, Verdict::TooHigh, 60),
+        (TOO_LOW, Verdict::TooLow, 60),
+        (WRONG, Verdict::Wrong, 5 * 60),
+        (WAIT, Verdict::Wait, 80),
+        (WRONG_LEVEL, Verdict::WrongLevel, 0),
+    ] {
+        assert_eq!(
+            parse_response(&page(article))?,
+            (verdict, cooldown),
+            "{article}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn unrecognised_response() {
+    assert!(parse_response(&page("Please don't repeatedly request this endpoint")).is_err());
+}
+
+#[test]
+fn cooldowns() {
+    for (text, cooldown) in [
+        ("You have 1m 20s left to wait.", 80),
+        ("You have 45s left to wait.", 45),
+        ("You have 2h 1m 5s left to wait.", 2 * 60 * 60 + 65),
+        ("Please wait one minute before trying again.", 60),
+        ("please wait 5 minutes before trying again.", 5 * 60),
+        ("please wait 30 seconds before trying again.", 30),
+        ("That's the right answer!", 0),
+    ] {
+        assert_eq!(parse_cooldown(text), cooldown, "{text}");
+    }
+}
+
+// a day's log with the given submissions and nothing on disk, since load finds no file
+fn log(submissions: &[(Part, Verdict, i64, &str)]) -> Result<VerdictLog, Error> {
+    let cache = Cache::new(&std::env::temp_dir().join("advent-2023-no-verdicts"));
+    let mut log = VerdictLog::load(&cache, 6, 2023)?;
+    assert!(log.submissions.is_empty());
+    log.submissions = submissions
+        .iter()
+        .map(|&(part, verdict, cooldown, answer)| Submission {
+            time: 1000,
+            part,
+            verdict,
+            cooldown,
+            answer: answer.into(),
+        })
+        .collect();
+    Ok(log)
+}
+
+#[test]
+fn bounds() -> Result<(), Error> {
+    let log = log(&[
+        (Part::A, Verdict::TooHigh, 60, "500"),
+        (Part::A, Verdict::TooLow, 60, "100"),
+        (Part::A, Verdict::Wrong, 60, "250"),
+    ])?;
+    let now = 2000;
+    for answer in ["100", "99", "500", "501", "250"] {
+        assert!(log.check(Part::A, answer, now).is_err(), "{answer}");
+    }
+    for answer in ["101", "499", "abc"] {
+        log.check(Part::A, answer, now)?;
+    }
+    // the other part's bounds don't apply
+    log.check(Part::B, "501", now)?;
+    Ok(())
+}
+
+#[test]
+fn already_solved() -> Result<(), Error> {
+    let log = log(&[(Part::A, Verdict::Correct, 0, "288")])?;
+    assert!(log.check(Part::A, "300", 2000).is_err());
+    log.check(Part::B, "71503", 2000)?;
+    Ok(())
+}
+
+#[test]
+fn cooling_down() -> Result<(), Error> {
+    let log = log(&[(Part::A, Verdict::Wait, 80, "300")])?;
+    assert!(log.check(Part::A, "301", 1079).is_err());
+    log.check(Part::A, "301", 1080)?;
+    Ok(())
+}